@@ -0,0 +1,276 @@
+/*
+ * C API for the human_name library.
+ *
+ * Generated from `src/external.rs` by cbindgen; don't edit by hand (see
+ * `cbindgen.toml`).
+ *
+ * Strings returned as `char *` are allocated by the library and must be
+ * released with `human_name_free_string`. Names returned by the parse
+ * functions must be released with `human_name_free_name`. Accessors for
+ * optional components return NULL when the component is absent.
+ *
+ * To avoid allocating, use the `_buf` accessors, which copy into a
+ * caller-provided buffer, or the `_ref` accessors, which borrow from the name.
+ *
+ * The `_buf` accessors copy a component plus a terminating NUL into `buf`,
+ * returning HUMAN_NAME_OK, HUMAN_NAME_ERROR_BUFFER_TOO_SMALL, or
+ * HUMAN_NAME_NOT_PRESENT for absent optional components. `out_len` (if not
+ * NULL) is always set to the length of the component in bytes, excluding the
+ * NUL, so callers can retry with a buffer of at least `*out_len + 1` bytes.
+ *
+ * The `_ref` accessors return a pointer to a component stored in the name,
+ * which is NOT NUL-terminated, valid until the name is freed, and must not be
+ * freed itself. They set `out_len` to the length in bytes, and return NULL
+ * (and a length of 0) for absent components.
+ */
+
+#ifndef HUMAN_NAME_H
+#define HUMAN_NAME_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+typedef struct HumanName HumanName;
+
+/* Initializes HumanNameParseOptions with the defaults for this version */
+#define HUMAN_NAME_PARSE_OPTIONS_INIT { HUMAN_NAME_API_VERSION, false }
+
+/**
+ * Version of the C API, incremented whenever functions or fields are added
+ */
+#define HUMAN_NAME_API_VERSION 4
+
+#define HUMAN_NAME_OK 0
+
+#define HUMAN_NAME_ERROR_NULL_INPUT 1
+
+#define HUMAN_NAME_ERROR_INVALID_UTF8 2
+
+#define HUMAN_NAME_ERROR_TOO_LONG 3
+
+#define HUMAN_NAME_ERROR_NO_LETTERS 4
+
+#define HUMAN_NAME_ERROR_UNPARSEABLE 5
+
+#define HUMAN_NAME_ERROR_UNSUPPORTED_VERSION 6
+
+#define HUMAN_NAME_ERROR_BUFFER_TOO_SMALL 7
+
+#define HUMAN_NAME_NOT_PRESENT 8
+
+#define HUMAN_NAME_ERROR_OVERFLOW 9
+
+/**
+ * Options for `human_name_parse_ex`.
+ *
+ * Callers must set `version` to the `HUMAN_NAME_API_VERSION` they were
+ * compiled against, so that fields can be added in future versions without
+ * breaking compatibility.
+ */
+typedef struct HumanNameParseOptions {
+  uint32_t version;
+  /**
+   * Keep titles and nicknames (see `human_name_honorific_prefix`, etc)
+   */
+  bool preserve_extras;
+} HumanNameParseOptions;
+
+/**
+ * Output of `human_name_parse_batch`, as parallel arrays allocated by the
+ * caller, each with room for one entry per input. Any of the arrays may be
+ * null if the caller doesn't need it, e.g. `names` when only the hashes
+ * are wanted.
+ */
+typedef struct HumanNameBatch {
+  HumanName **names;
+  int *errors;
+  uint64_t *hashes;
+} HumanNameBatch;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Parses a name, returning null if the input can't be parsed.
+ */
+HumanName *human_name_parse(const char *input);
+
+/**
+ * Version of the library's C API, to compare with `HUMAN_NAME_API_VERSION`
+ */
+uint32_t human_name_api_version(void);
+
+/**
+ * Like `human_name_parse`, but accepts options (which may be null, for the
+ * defaults) and reports the reason for any failure via `error` (which may
+ * also be null).
+ */
+HumanName *human_name_parse_ex(const char *input,
+                               const struct HumanNameParseOptions *options,
+                               int *error);
+
+/**
+ * Parses `n` inputs, filling in the caller's arrays in `out` (see
+ * `HumanNameBatch`). Failed entries get a null name, an error code as from
+ * `human_name_parse_ex`, and a hash of zero. Returns the number of inputs
+ * successfully parsed. Each name must be freed, e.g. by passing the names
+ * array to `human_name_free_batch`.
+ */
+size_t human_name_parse_batch(const char *const *inputs,
+                              size_t n,
+                              const struct HumanNameParseOptions *options,
+                              const struct HumanNameBatch *out);
+
+/**
+ * Frees each non-null name in an array of `n` names (but not the array
+ * itself), e.g. as filled in by `human_name_parse_batch`.
+ */
+void human_name_free_batch(HumanName **names, size_t n);
+
+/**
+ * For each of `n_a` names in `a` and `n_b` names in `b`, writes whether
+ * they are consistent to `out`, which must have room for `n_a * n_b`
+ * entries, in row-major order (i.e. `out[i * n_b + j]` compares `a[i]` to
+ * `b[j]`). Null names are consistent with nothing.
+ *
+ * Returns `HUMAN_NAME_OK`, or, without writing anything,
+ * `HUMAN_NAME_ERROR_OVERFLOW` if `n_a * n_b` overflows or
+ * `HUMAN_NAME_ERROR_NULL_INPUT` if any array is null when there are entries
+ * to compare.
+ */
+int human_name_match_matrix(const HumanName *const *a,
+                            size_t n_a,
+                            const HumanName *const *b,
+                            size_t n_b,
+                            bool *out);
+
+/**
+ * Groups `n` names into clusters of possibly-identical names, writing a
+ * cluster id for each name to `out_cluster_ids`, which must have room for
+ * `n` entries. Cluster ids are numbered from zero in order of first
+ * appearance. Returns the number of clusters.
+ *
+ * Because consistency isn't transitive, clusters are connected components:
+ * "J. Doe" will put "Jane Doe" and "John Doe" in the same cluster, even
+ * though they aren't consistent with each other. Null names each get a
+ * cluster of their own.
+ */
+size_t human_name_cluster(const HumanName *const *names, size_t n, size_t *out_cluster_ids);
+
+/**
+ * Returns a static description of an error code from `human_name_parse_ex`,
+ * which must not be freed.
+ */
+const char *human_name_error_message(int error);
+
+void human_name_free_name(HumanName *name_ptr);
+
+void human_name_free_string(char *str_ptr);
+
+bool human_name_consistent_with(const HumanName *a, const HumanName *b);
+
+uint64_t human_name_hash(const HumanName *name);
+
+char *human_name_surname(const HumanName *name);
+
+char *human_name_given_name(const HumanName *name);
+
+char *human_name_initials(const HumanName *name);
+
+char *human_name_first_initial(const HumanName *name);
+
+char *human_name_middle_initials(const HumanName *name);
+
+bool human_name_goes_by_middle_name(const HumanName *name);
+
+bool human_name_matches_slug_or_localpart(const HumanName *name, const char *input);
+
+char *human_name_middle_names(const HumanName *name);
+
+char *human_name_suffix(const HumanName *name);
+
+char *human_name_display_first_last(const HumanName *name);
+
+char *human_name_display_initial_surname(const HumanName *name);
+
+char *human_name_display_full(const HumanName *name);
+
+uint32_t human_name_byte_len(const HumanName *name);
+
+/**
+ * Only present if parsed with `preserve_extras`
+ */
+char *human_name_honorific_prefix(const HumanName *name);
+
+/**
+ * Only present if parsed with `preserve_extras`
+ */
+char *human_name_honorific_suffix(const HumanName *name);
+
+/**
+ * Only present if parsed with `preserve_extras`
+ */
+char *human_name_nickname(const HumanName *name);
+
+int human_name_surname_buf(const HumanName *name, char *buf, size_t buf_len, size_t *out_len);
+
+int human_name_given_name_buf(const HumanName *name, char *buf, size_t buf_len, size_t *out_len);
+
+int human_name_initials_buf(const HumanName *name, char *buf, size_t buf_len, size_t *out_len);
+
+int human_name_first_initial_buf(const HumanName *name, char *buf, size_t buf_len, size_t *out_len);
+
+int human_name_middle_initials_buf(const HumanName *name,
+                                   char *buf,
+                                   size_t buf_len,
+                                   size_t *out_len);
+
+int human_name_middle_names_buf(const HumanName *name, char *buf, size_t buf_len, size_t *out_len);
+
+int human_name_suffix_buf(const HumanName *name, char *buf, size_t buf_len, size_t *out_len);
+
+int human_name_display_first_last_buf(const HumanName *name,
+                                      char *buf,
+                                      size_t buf_len,
+                                      size_t *out_len);
+
+int human_name_display_initial_surname_buf(const HumanName *name,
+                                           char *buf,
+                                           size_t buf_len,
+                                           size_t *out_len);
+
+int human_name_display_full_buf(const HumanName *name, char *buf, size_t buf_len, size_t *out_len);
+
+int human_name_honorific_prefix_buf(const HumanName *name,
+                                    char *buf,
+                                    size_t buf_len,
+                                    size_t *out_len);
+
+int human_name_honorific_suffix_buf(const HumanName *name,
+                                    char *buf,
+                                    size_t buf_len,
+                                    size_t *out_len);
+
+int human_name_nickname_buf(const HumanName *name, char *buf, size_t buf_len, size_t *out_len);
+
+const char *human_name_given_name_ref(const HumanName *name, size_t *out_len);
+
+const char *human_name_initials_ref(const HumanName *name, size_t *out_len);
+
+const char *human_name_middle_initials_ref(const HumanName *name, size_t *out_len);
+
+const char *human_name_suffix_ref(const HumanName *name, size_t *out_len);
+
+const char *human_name_honorific_prefix_ref(const HumanName *name, size_t *out_len);
+
+const char *human_name_honorific_suffix_ref(const HumanName *name, size_t *out_len);
+
+const char *human_name_nickname_ref(const HumanName *name, size_t *out_len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* HUMAN_NAME_H */
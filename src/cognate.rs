@@ -0,0 +1,402 @@
+use phf;
+
+// Given names which are equivalent across languages, and so may refer to the
+// same person in records kept in different countries ("Juan" and "John"), or
+// transliterated from different scripts ("Ioannis" and "Yohanan").
+//
+// Unlike nicknames, these aren't used unless requested (see
+// `ComparisonOptions`), since e.g. a "Jan" and a "Johannes" in the same
+// Dutch family are likely to be different people.
+
+// Takes ASCII-transliterated names, as the nickname logic does
+pub fn are_cognates(a: &str, b: &str) -> bool {
+    match (COGNATE_GROUPS.get(a), COGNATE_GROUPS.get(b)) {
+        (Some(group_a), Some(group_b)) => group_a == group_b,
+        _ => false,
+    }
+}
+
+// Maps each name to the English form of the name, which serves to identify
+// its group of equivalents. Masculine and feminine forms are kept separate.
+//
+// Names which are also in use in English with another meaning are left out
+// ("Jean" is as often the feminine English name as the French "John"), as are
+// names that only belong to a group through some other form ("Yuri" comes
+// from "Georgiy", but is its own name in Russian).
+static COGNATE_GROUPS: phf::Map<&'static str, &'static str> = phf_map! {
+    "Aindrea" => "Andrew",
+    "Alasdair" => "Alexander",
+    "Alejandro" => "Alexander",
+    "Aleksander" => "Alexander",
+    "Aleksandr" => "Alexander",
+    "Alessandro" => "Alexander",
+    "Alexander" => "Alexander",
+    "Alexandr" => "Alexander",
+    "Alexandre" => "Alexander",
+    "Alexandros" => "Alexander",
+    "Alistair" => "Alexander",
+    "Ana" => "Anne",
+    "Anders" => "Andrew",
+    "Andras" => "Andrew",
+    "Andre" => "Andrew",
+    "Andreas" => "Andrew",
+    "Andrei" => "Andrew",
+    "Andreu" => "Andrew",
+    "Andrew" => "Andrew",
+    "Andrey" => "Andrew",
+    "Andries" => "Andrew",
+    "Andrzej" => "Andrew",
+    "Ann" => "Anne",
+    "Anna" => "Anne",
+    "Anne" => "Anne",
+    "Antal" => "Anthony",
+    "Antanas" => "Anthony",
+    "Anthony" => "Anthony",
+    "Antoine" => "Anthony",
+    "Anton" => "Anthony",
+    "Antoni" => "Anthony",
+    "Antonin" => "Anthony",
+    "Antonio" => "Anthony",
+    "Antonios" => "Anthony",
+    "Antony" => "Anthony",
+    "Carl" => "Charles",
+    "Carles" => "Charles",
+    "Carlo" => "Charles",
+    "Carlos" => "Charles",
+    "Catalina" => "Catherine",
+    "Caterina" => "Catherine",
+    "Catherine" => "Catherine",
+    "Charles" => "Charles",
+    "Christoffer" => "Christopher",
+    "Christoph" => "Christopher",
+    "Christophe" => "Christopher",
+    "Christopher" => "Christopher",
+    "Cristobal" => "Christopher",
+    "Cristoforo" => "Christopher",
+    "Edoardo" => "Edward",
+    "Edouard" => "Edward",
+    "Eduard" => "Edward",
+    "Eduardo" => "Edward",
+    "Edward" => "Edward",
+    "Ekaterina" => "Catherine",
+    "Elena" => "Helen",
+    "Eleni" => "Helen",
+    "Elisabet" => "Elizabeth",
+    "Elisabeth" => "Elizabeth",
+    "Elisabetta" => "Elizabeth",
+    "Elisavet" => "Elizabeth",
+    "Elizabeth" => "Elizabeth",
+    "Elizaveta" => "Elizabeth",
+    "Elzbieta" => "Elizabeth",
+    "Enric" => "Henry",
+    "Enrico" => "Henry",
+    "Enrique" => "Henry",
+    "Eoin" => "John",
+    "Erzsebet" => "Elizabeth",
+    "Esteban" => "Stephen",
+    "Esteve" => "Stephen",
+    "Etienne" => "Stephen",
+    "Evan" => "John",
+    "Federico" => "Frederick",
+    "Ferenc" => "Francis",
+    "Foma" => "Thomas",
+    "Francesc" => "Francis",
+    "Francesco" => "Francis",
+    "Francis" => "Francis",
+    "Francisc" => "Francis",
+    "Francisco" => "Francis",
+    "Franciszek" => "Francis",
+    "Francois" => "Francis",
+    "Frans" => "Francis",
+    "Frantisek" => "Francis",
+    "Franz" => "Francis",
+    "Frederic" => "Frederick",
+    "Frederick" => "Frederick",
+    "Frederik" => "Frederick",
+    "Fredrik" => "Frederick",
+    "Friedrich" => "Frederick",
+    "Fryderyk" => "Frederick",
+    "Georg" => "George",
+    "George" => "George",
+    "Georges" => "George",
+    "Georgios" => "George",
+    "Gergely" => "Gregory",
+    "Gheorghe" => "George",
+    "Giacomo" => "James",
+    "Giannis" => "John",
+    "Giorgio" => "George",
+    "Giovanna" => "Jane",
+    "Giovanni" => "John",
+    "Giuseppe" => "Joseph",
+    "Giuseppina" => "Josephine",
+    "Gregoire" => "Gregory",
+    "Gregor" => "Gregory",
+    "Gregorio" => "Gregory",
+    "Gregory" => "Gregory",
+    "Grigori" => "Gregory",
+    "Grigorios" => "Gregory",
+    "Grigory" => "Gregory",
+    "Grzegorz" => "Gregory",
+    "Guglielmo" => "William",
+    "Guillaume" => "William",
+    "Guillermo" => "William",
+    "Gwilym" => "William",
+    "Gyorgy" => "George",
+    "Hanna" => "Anne",
+    "Hannah" => "Anne",
+    "Hans" => "John",
+    "Heinrich" => "Henry",
+    "Helen" => "Helen",
+    "Helena" => "Helen",
+    "Helene" => "Helen",
+    "Hendrik" => "Henry",
+    "Henri" => "Henry",
+    "Henrik" => "Henry",
+    "Henrique" => "Henry",
+    "Henry" => "Henry",
+    "Henryk" => "Henry",
+    "Iago" => "James",
+    "Iain" => "John",
+    "Iakovos" => "James",
+    "Ian" => "John",
+    "Ilona" => "Helen",
+    "Ioan" => "John",
+    "Ioanna" => "Jane",
+    "Ioannis" => "John",
+    "Iosif" => "Joseph",
+    "Isabel" => "Elizabeth",
+    "Isabella" => "Elizabeth",
+    "Isabelle" => "Elizabeth",
+    "Istvan" => "Stephen",
+    "Ivan" => "John",
+    "Ivana" => "Jane",
+    "Jacob" => "James",
+    "Jacopo" => "James",
+    "Jacques" => "James",
+    "Jaime" => "James",
+    "Jakob" => "James",
+    "Jakub" => "James",
+    "James" => "James",
+    "Jan" => "John",
+    "Jana" => "Jane",
+    "Jane" => "Jane",
+    "Janina" => "Jane",
+    "Janos" => "John",
+    "Jaume" => "James",
+    "Jeanne" => "Jane",
+    "Jens" => "John",
+    "Jerzy" => "George",
+    "Jindrich" => "Henry",
+    "Jiri" => "George",
+    "Joan" => "Jane",
+    "Joanna" => "Jane",
+    "Joao" => "John",
+    "Johan" => "John",
+    "Johann" => "John",
+    "Johanna" => "Jane",
+    "Johannes" => "John",
+    "John" => "John",
+    "Jon" => "John",
+    "Jordi" => "George",
+    "Jorge" => "George",
+    "Joris" => "George",
+    "Jose" => "Joseph",
+    "Josef" => "Joseph",
+    "Josefa" => "Josephine",
+    "Josefina" => "Josephine",
+    "Josep" => "Joseph",
+    "Joseph" => "Joseph",
+    "Josephine" => "Josephine",
+    "Jovan" => "John",
+    "Jozef" => "Joseph",
+    "Jozefa" => "Josephine",
+    "Jozsef" => "Joseph",
+    "Juan" => "John",
+    "Juana" => "Jane",
+    "Juhani" => "John",
+    "Jurgen" => "George",
+    "Jurij" => "George",
+    "Karel" => "Charles",
+    "Karl" => "Charles",
+    "Karol" => "Charles",
+    "Katalin" => "Catherine",
+    "Katarzyna" => "Catherine",
+    "Katerina" => "Catherine",
+    "Kateryna" => "Catherine",
+    "Katharina" => "Catherine",
+    "Katherine" => "Catherine",
+    "Katrin" => "Catherine",
+    "Kristof" => "Christopher",
+    "Krystof" => "Christopher",
+    "Krzysztof" => "Christopher",
+    "Lajos" => "Louis",
+    "Lars" => "Lawrence",
+    "Laurence" => "Lawrence",
+    "Laurens" => "Lawrence",
+    "Laurent" => "Lawrence",
+    "Lawrence" => "Lawrence",
+    "Liam" => "William",
+    "Lluis" => "Louis",
+    "Lodewijk" => "Louis",
+    "Lorenz" => "Lawrence",
+    "Lorenzo" => "Lawrence",
+    "Louis" => "Louis",
+    "Ludovic" => "Louis",
+    "Ludovico" => "Louis",
+    "Ludvik" => "Louis",
+    "Ludwig" => "Louis",
+    "Ludwik" => "Louis",
+    "Luigi" => "Louis",
+    "Luis" => "Louis",
+    "Luiz" => "Louis",
+    "Maciej" => "Matthew",
+    "Maire" => "Mary",
+    "Malgorzata" => "Margaret",
+    "Margaret" => "Margaret",
+    "Margareta" => "Margaret",
+    "Margarete" => "Margaret",
+    "Margarethe" => "Margaret",
+    "Margarita" => "Margaret",
+    "Margherita" => "Margaret",
+    "Margit" => "Margaret",
+    "Marguerite" => "Margaret",
+    "Maria" => "Mary",
+    "Mariam" => "Mary",
+    "Marie" => "Mary",
+    "Marija" => "Mary",
+    "Mariya" => "Mary",
+    "Marketa" => "Margaret",
+    "Mary" => "Mary",
+    "Maryam" => "Mary",
+    "Matej" => "Matthew",
+    "Mateo" => "Matthew",
+    "Mateusz" => "Matthew",
+    "Mathias" => "Matthew",
+    "Mathieu" => "Matthew",
+    "Matteo" => "Matthew",
+    "Matthaus" => "Matthew",
+    "Matthew" => "Matthew",
+    "Matthias" => "Matthew",
+    "Mattias" => "Matthew",
+    "Matvei" => "Matthew",
+    "Michael" => "Michael",
+    "Michal" => "Michael",
+    "Michel" => "Michael",
+    "Michele" => "Michael",
+    "Miguel" => "Michael",
+    "Mihai" => "Michael",
+    "Mihaly" => "Michael",
+    "Mikael" => "Michael",
+    "Mikhail" => "Michael",
+    "Mikkel" => "Michael",
+    "Mikolaj" => "Nicholas",
+    "Miriam" => "Mary",
+    "Niccolo" => "Nicholas",
+    "Nicholas" => "Nicholas",
+    "Nicola" => "Nicholas",
+    "Nicolae" => "Nicholas",
+    "Nicolas" => "Nicholas",
+    "Nikola" => "Nicholas",
+    "Nikolai" => "Nicholas",
+    "Nikolaos" => "Nicholas",
+    "Nikolaus" => "Nicholas",
+    "Nikolay" => "Nicholas",
+    "Ondrej" => "Andrew",
+    "Osip" => "Joseph",
+    "Pablo" => "Paul",
+    "Pal" => "Paul",
+    "Paolo" => "Paul",
+    "Pau" => "Paul",
+    "Paul" => "Paul",
+    "Paulo" => "Paul",
+    "Pavel" => "Paul",
+    "Pavlos" => "Paul",
+    "Pawel" => "Paul",
+    "Peadar" => "Peter",
+    "Peder" => "Peter",
+    "Pedro" => "Peter",
+    "Per" => "Peter",
+    "Pere" => "Peter",
+    "Petar" => "Peter",
+    "Peter" => "Peter",
+    "Petr" => "Peter",
+    "Petros" => "Peter",
+    "Pier" => "Peter",
+    "Pierre" => "Peter",
+    "Piers" => "Peter",
+    "Pieter" => "Peter",
+    "Pietro" => "Peter",
+    "Piotr" => "Peter",
+    "Pyotr" => "Peter",
+    "Ricardo" => "Richard",
+    "Riccardo" => "Richard",
+    "Richard" => "Richard",
+    "Rikard" => "Richard",
+    "Robert" => "Robert",
+    "Roberto" => "Robert",
+    "Rupert" => "Robert",
+    "Ruprecht" => "Robert",
+    "Ryszard" => "Richard",
+    "Sandor" => "Alexander",
+    "Seamus" => "James",
+    "Sean" => "John",
+    "Siobhan" => "Jane",
+    "Stefan" => "Stephen",
+    "Stefano" => "Stephen",
+    "Stefanos" => "Stephen",
+    "Stepan" => "Stephen",
+    "Stephan" => "Stephen",
+    "Stephen" => "Stephen",
+    "Steven" => "Stephen",
+    "Szczepan" => "Stephen",
+    "Tamas" => "Thomas",
+    "Thomas" => "Thomas",
+    "Tomas" => "Thomas",
+    "Tomasz" => "Thomas",
+    "Tommaso" => "Thomas",
+    "Vicente" => "Vincent",
+    "Vilem" => "William",
+    "Vilhelm" => "William",
+    "Vincent" => "Vincent",
+    "Vincenzo" => "Vincent",
+    "Vinzenz" => "Vincent",
+    "Wawrzyniec" => "Lawrence",
+    "Wilhelm" => "William",
+    "Willem" => "William",
+    "William" => "William",
+    "Wincenty" => "Vincent",
+    "Xose" => "Joseph",
+    "Yakov" => "James",
+    "Yekaterina" => "Catherine",
+    "Yelena" => "Helen",
+    "Yiannis" => "John",
+    "Yochanan" => "John",
+    "Yohanan" => "John",
+    "Yosef" => "Joseph",
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cognates() {
+        assert!(are_cognates("Juan", "John"));
+        assert!(are_cognates("Giovanni", "Johann"));
+        assert!(are_cognates("Yohanan", "Ioannis"));
+        assert!(are_cognates("Jan", "Ivan"));
+        assert!(are_cognates("Giuseppe", "Jose"));
+        assert!(are_cognates("Wilhelm", "Guillermo"));
+    }
+
+    #[test]
+    fn non_cognates() {
+        assert!(!are_cognates("Juan", "Juana"));
+        assert!(!are_cognates("John", "Joseph"));
+        assert!(!are_cognates("Jane", "John"));
+        assert!(!are_cognates("Juan", "Jorge"));
+        assert!(!are_cognates("Bob", "Robert"));
+        assert!(!are_cognates("Jean", "John"));
+        assert!(!are_cognates("Yuri", "George"));
+    }
+}
@@ -1,13 +1,64 @@
-use std::ascii::AsciiExt;
 use std::borrow::Cow;
+#[allow(deprecated)]
+use std::hash::{Hasher, SipHasher};
 use super::utils::*;
-use super::nickname::have_matching_variants;
+use super::nickname::{have_matching_variants_in, NicknameDb};
+use super::diminutive::Language;
+use super::cognate;
+use super::surname::strip_gendered_ending;
+use super::transliteration::{transliterate_with, Transliterator};
 use super::{Name, NameWordOrInitial};
+use unicode_normalization::UnicodeNormalization;
 use unicode_segmentation::UnicodeSegmentation;
 
 pub const MIN_SURNAME_CHAR_MATCH: usize = 4;
 pub const MIN_GIVEN_NAME_CHAR_MATCH: usize = 3;
 
+/// Options controlling the behavior of `Name::consistent_with_options`.
+///
+/// The default options give the same behavior as `Name::consistent_with`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ComparisonOptions<'a> {
+    /// Nicknames to recognize in addition to, or instead of, the built-in
+    /// table (see `NicknameDb`)
+    pub nicknames: Option<&'a NicknameDb>,
+
+    /// Languages whose diminutive forms of given names should be recognized,
+    /// in addition to common English and Spanish forms (see `Language`)
+    pub languages: &'a [Language],
+
+    /// Whether to treat equivalent given names in different languages
+    /// ("Juan" and "John") as consistent (see `MatchKind::Cognate`)
+    pub cognates: bool,
+
+    /// A scheme for transliterating surnames and given names before comparing
+    /// them, in place of unidecode (see `Transliterator`). Initials are
+    /// always compared using unidecode.
+    pub transliterator: Option<&'a dyn Transliterator>,
+
+    /// Whether to treat masculine and feminine forms of Slavic and Baltic
+    /// surnames as consistent ("Novák" and "Nováková", "Kowalski" and
+    /// "Kowalska", "Kazlauskas" and "Kazlauskienė"). Only endings paired
+    /// with a masculine form in one of these languages are recognized, so
+    /// "Martin" and "Marta" are still inconsistent, but a few other surnames
+    /// that happen to look like such a pair will be treated as consistent
+    /// ("Martin" and "Martina").
+    pub gendered_surnames: bool,
+}
+
+/// How two consistent names were matched, from strongest to weakest evidence
+/// (see `Name::match_kind`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MatchKind {
+    /// Given and middle names are equal, or one is a prefix or initial of
+    /// the other
+    Consistent,
+    /// A given or middle name matched only as a nickname or diminutive
+    Nickname,
+    /// A given or middle name matched only as an equivalent in another
+    /// language (only with `ComparisonOptions::cognates`)
+    Cognate,
+}
 
 impl Name {
 
@@ -44,6 +95,8 @@ impl Name {
     /// Transliterates everything to ASCII before comparison using the naive
     /// algorithm of [unidecode](https://github.com/chowdhurya/rust-unidecode/)
     /// (which ignores context), and ignores case, accents and combining marks.
+    /// Other schemes can be chosen with `consistent_with_options` (see
+    /// `Transliterator`).
     ///
     /// In the case of given and middle names, allows one name to be a prefix of
     /// the other, without requiring the prefix end at a word boundary as we do
@@ -62,21 +115,79 @@ impl Name {
     /// are trying to figure out exactly where, e.g. a particular author's index
     /// in the list of authors of a co-authored paper.
     ///
-    #[cfg_attr(rustfmt, rustfmt_skip)]
+    #[rustfmt::skip]
     pub fn consistent_with(&self, other: &Name) -> bool {
+        self.consistent_with_options(other, &ComparisonOptions::default())
+    }
+
+    /// As `consistent_with`, but with non-default options (see
+    /// `ComparisonOptions`).
+    #[rustfmt::skip]
+    pub fn consistent_with_options(&self, other: &Name, options: &ComparisonOptions) -> bool {
+        self.consistent_with_up_to(other, options, MatchKind::Cognate)
+    }
+
+    /// If this name is consistent with another (see
+    /// `consistent_with_options`), how strong is the match?
+    ///
+    /// Callers may want to weigh nickname and cognate matches lower than
+    /// other matches, since e.g. "Juan Garcia" is more likely to be "Juan
+    /// Garcia" than to be "John Garcia".
+    ///
+    /// # Examples
+    /// ```
+    /// use human_name::{Name, ComparisonOptions, MatchKind};
+    ///
+    /// let options = ComparisonOptions { cognates: true, ..ComparisonOptions::default() };
+    /// let juan = Name::parse("Juan Garcia").unwrap();
+    /// let john = Name::parse("John Garcia").unwrap();
+    /// let jack = Name::parse("Jack Garcia").unwrap();
+    /// let j = Name::parse("J. Garcia").unwrap();
+    ///
+    /// assert_eq!(Some(MatchKind::Cognate), juan.match_kind(&john, &options));
+    /// assert_eq!(Some(MatchKind::Nickname), jack.match_kind(&john, &options));
+    /// assert_eq!(Some(MatchKind::Consistent), j.match_kind(&john, &options));
+    /// assert_eq!(None, juan.match_kind(&john, &ComparisonOptions::default()));
+    /// ```
+    pub fn match_kind(&self, other: &Name, options: &ComparisonOptions) -> Option<MatchKind> {
+        let kinds = [MatchKind::Consistent, MatchKind::Nickname, MatchKind::Cognate];
+
+        // Most pairs of names aren't consistent at all, so rule that out in
+        // a single pass before looking for the strongest kind of match
+        if !self.consistent_with_up_to(other, options, MatchKind::Cognate) {
+            None
+        } else {
+            kinds.iter().cloned().find(|&kind| self.consistent_with_up_to(other, options, kind))
+        }
+    }
+
+    #[rustfmt::skip]
+    fn consistent_with_up_to(&self,
+                             other: &Name,
+                             options: &ComparisonOptions,
+                             max_kind: MatchKind)
+                             -> bool {
         // Fast path
-        if self.memoized_surname_hash() != other.memoized_surname_hash() {
+        if options.transliterator.is_some() || options.gendered_surnames {
+            if surname_hash_with_options(self, options) != surname_hash_with_options(other, options) {
+                return false;
+            }
+        } else if self.memoized_surname_hash() != other.memoized_surname_hash() {
             return false;
         }
 
         // Check given name(s) first because if we got this far, we know that
         // at least the last characters of the surnames are consistent
-        self.given_and_middle_names_consistent(other) &&
-        self.surname_consistent(other) &&
+        self.given_and_middle_names_consistent(other, options, max_kind) &&
+        self.surname_consistent(other, options) &&
         self.suffix_consistent(other)
     }
 
-    fn given_and_middle_names_consistent(&self, other: &Name) -> bool {
+    fn given_and_middle_names_consistent(&self,
+                                         other: &Name,
+                                         options: &ComparisonOptions,
+                                         max_kind: MatchKind)
+                                         -> bool {
         // Handle simple cases first, where we only have to worry about one name
         // and/or initial.
         if self.middle_initials().is_none() && other.middle_initials().is_none() {
@@ -84,21 +195,27 @@ impl Name {
                 return to_ascii_letter(self.first_initial()) ==
                        to_ascii_letter(other.first_initial());
             } else {
-                return have_matching_variants(self.given_name().unwrap(),
-                                              other.given_name().unwrap());
+                return given_names_match(self.given_name().unwrap(),
+                                         other.given_name().unwrap(),
+                                         options,
+                                         max_kind);
             }
         }
 
         // For the more complicated cases, we'll simplify things a bit by
         // letting ourselves assume `self` has the more complete name.
         if self.initials().chars().count() >= other.initials().chars().count() {
-            self.given_and_middle_names_consistent_with_less_complete(other)
+            self.given_and_middle_names_consistent_with_less_complete(other, options, max_kind)
         } else {
-            other.given_and_middle_names_consistent_with_less_complete(self)
+            other.given_and_middle_names_consistent_with_less_complete(self, options, max_kind)
         }
     }
 
-    fn given_and_middle_names_consistent_with_less_complete(&self, other: &Name) -> bool {
+    fn given_and_middle_names_consistent_with_less_complete(&self,
+                                                             other: &Name,
+                                                             options: &ComparisonOptions,
+                                                             max_kind: MatchKind)
+                                                             -> bool {
         // Check initials first
         if !self.initials_consistent_with_less_complete(other) {
             return false;
@@ -125,7 +242,10 @@ impl Name {
 
         for my_part in self.given_names_or_initials() {
             if let Some(ref their_part) = their_part_if_any {
-                let result = my_part.check_consistency(their_part, !looked_up_nicknames);
+                let result = my_part.check_consistency(their_part,
+                                                       !looked_up_nicknames,
+                                                       options,
+                                                       max_kind);
 
                 match result {
                     ComparisonResult::Inconsistent => {
@@ -216,7 +336,7 @@ impl Name {
                 // However, only check in this direction because we know `self`
                 // has more complete initials, so their initials won't contain
                 // ours unless they're equal.
-                if !my_initials.contains(&their_initials) {
+                if !my_initials.contains(their_initials) {
                     return false;
                 }
             } else {
@@ -239,7 +359,7 @@ impl Name {
         true
     }
 
-    fn transliterated_initials(&self) -> Cow<str> {
+    fn transliterated_initials(&self) -> Cow<'_, str> {
         if self.initials().is_ascii() {
             Cow::Borrowed(self.initials())
         } else {
@@ -272,28 +392,28 @@ impl Name {
         self.surname_index > prev
     }
 
-    fn surname_consistent(&self, other: &Name) -> bool {
-        // Fast path
-        if self.simple_surname() && other.simple_surname() {
+    fn surname_consistent(&self, other: &Name, options: &ComparisonOptions) -> bool {
+        // Fast path, for ASCII surnames whose spelling can't be folded
+        if self.simple_surname() && other.simple_surname() && options.transliterator.is_none() &&
+           !options.gendered_surnames && !has_foldable_digraph(&*self.surname()) &&
+           !has_foldable_digraph(&*other.surname()) {
             return self.surname().eq_ignore_ascii_case(&*other.surname());
         }
 
-        let mut my_words = self.surnames()
-                               .iter()
-                               .flat_map(|w| w.unicode_words())
-                               .rev();
+        let my_surnames = comparable_surname_words(self, options);
+        let mut my_words = my_surnames.iter().rev();
 
-        let mut their_words = other.surnames()
-                                   .iter()
-                                   .flat_map(|w| w.unicode_words())
-                                   .rev();
+        let their_surnames = comparable_surname_words(other, options);
+        let mut their_words = their_surnames.iter().rev();
 
         let mut my_word = my_words.next();
         let mut their_word = their_words.next();
         let mut matching_chars = 0;
 
-        // Require either an exact match (ignoring case etc), or a partial match
-        // of len >= MIN_SURNAME_CHAR_MATCH and breaking on a word boundary
+        // Require either an exact match (ignoring case, umlauts etc,
+        // and gendered endings if requested), or
+        // a partial match of len >= MIN_SURNAME_CHAR_MATCH and breaking on a
+        // word boundary
         loop {
             // No words remaining for some surname - that's ok if it's true of
             // both, or if the components that match are long enough
@@ -305,7 +425,7 @@ impl Name {
 
             macro_rules! reverse_lowercase_alpha_chars {
                 ($word:expr) => {
-                    $word.unwrap().chars().flat_map(transliterate).rev().filter_map(lowercase_if_alpha)
+                    $word.unwrap().chars().rev().peekable()
                 }
             }
 
@@ -349,10 +469,20 @@ impl Name {
                         their_chars = reverse_lowercase_alpha_chars!(their_word);
                         their_char = their_chars.next();
                     }
-                } else if my_char != their_char {
-                    // We found a conflict and can short-circuit
-                    return false;
                 } else {
+                    let (m, t) = (my_char.unwrap(), their_char.unwrap());
+
+                    // An umlaut or "å" matches its digraph spelling on the
+                    // other side (reading backwards, so "e" then "u" for "ü")
+                    if spells_digraph(m, their_chars.peek(), t) {
+                        their_chars.next();
+                    } else if spells_digraph(t, my_chars.peek(), m) {
+                        my_chars.next();
+                    } else if unmarked_letter(m) != unmarked_letter(t) {
+                        // We found a conflict and can short-circuit
+                        return false;
+                    }
+
                     // Characters matched, continue the inner loop
                     matching_chars += 1;
                     my_char = my_chars.next();
@@ -372,8 +502,71 @@ impl Name {
     }
 }
 
+#[allow(deprecated)]
+fn surname_hash_with_options(name: &Name, options: &ComparisonOptions) -> u64 {
+    let mut s = SipHasher::new();
+    name.surname_hash_with_options(options, &mut s);
+    s.finish()
+}
+
+// The words of a surname as we compare them: in their comparable spelling
+// (see `comparable_spelling`), and optionally without gendered endings
+pub fn comparable_surname_words(name: &Name, options: &ComparisonOptions) -> Vec<String> {
+    name.surnames()
+        .iter()
+        .flat_map(|w| w.unicode_words())
+        .map(|w| {
+            let word = transliterate_with(w, options.transliterator);
+            if options.gendered_surnames {
+                // Before transliterating to ASCII, since some endings are
+                // told apart by their accents
+                let word: String = word.to_lowercase().nfkd().collect();
+                comparable_spelling(&strip_gendered_ending(&word))
+            } else {
+                comparable_spelling(&word)
+            }
+        })
+        .collect()
+}
+
+// Whether a letter kept by `comparable_spelling` is spelled out by the
+// digraph `first`, `second` on the other side
+fn spells_digraph(letter: char, first: Option<&char>, second: char) -> bool {
+    match vowel_digraph(letter) {
+        Some((a, b)) => first == Some(&a) && second == b,
+        None => false,
+    }
+}
+
+// Whether an ASCII surname contains a spelling folded by
+// `comparable_spelling`, so can't be compared as is
+fn has_foldable_digraph(surname: &str) -> bool {
+    surname.as_bytes().windows(4).any(|w| w.eq_ignore_ascii_case(b"aard"))
+}
+
+// Compares whole given or middle names, allowing matches of no weaker kind
+// than `max_kind`
+fn given_names_match(a: &str, b: &str, options: &ComparisonOptions, max_kind: MatchKind) -> bool {
+    let a = transliterate_with(a, options.transliterator);
+    let b = transliterate_with(b, options.transliterator);
+    let ascii_a = to_ascii(&*a);
+    let ascii_b = to_ascii(&*b);
+
+    if expand_umlauts(&*a) == expand_umlauts(&*b) {
+        true
+    } else if max_kind == MatchKind::Consistent {
+        eq_or_starts_with!(ascii_a, ascii_b)
+    } else if have_matching_variants_in(&*ascii_a, &*ascii_b, options.nicknames, options.languages) {
+        true
+    } else if max_kind == MatchKind::Cognate && options.cognates {
+        cognate::are_cognates(&*ascii_a, &*ascii_b)
+    } else {
+        false
+    }
+}
+
 #[derive(Eq,PartialEq,Debug)]
-enum ComparisonResult {
+pub enum ComparisonResult {
     Inconsistent,
     DifferentInitials,
     InitialsOnlyMatch,
@@ -385,11 +578,11 @@ enum ComparisonResult {
 
 impl<'a> NameWordOrInitial<'a> {
     pub fn initial(&self) -> Option<char> {
-        match self {
-            &NameWordOrInitial::Word(word, _) => {
-                word.chars().nth(0).and_then(|c| to_ascii_letter(c))
+        match *self {
+            NameWordOrInitial::Word(word, _) => {
+                word.chars().nth(0).and_then(to_ascii_letter)
             }
-            &NameWordOrInitial::Initial(initial) => {
+            NameWordOrInitial::Initial(initial) => {
                 to_ascii_letter(initial)
             }
         }
@@ -397,7 +590,9 @@ impl<'a> NameWordOrInitial<'a> {
 
     pub fn check_consistency(&self,
                              other: &NameWordOrInitial,
-                             allow_nicknames: bool)
+                             allow_nicknames: bool,
+                             options: &ComparisonOptions,
+                             max_kind: MatchKind)
                              -> ComparisonResult {
         if self.initial().is_none() || self.initial() != other.initial() {
             return ComparisonResult::DifferentInitials;
@@ -407,12 +602,13 @@ impl<'a> NameWordOrInitial<'a> {
             return ComparisonResult::InitialsOnlyMatch;
         }
 
-        let mut my_chars = self.word()
-                               .chars()
+        let my_word = transliterate_with(self.word(), options.transliterator);
+        let their_word = transliterate_with(other.word(), options.transliterator);
+
+        let mut my_chars = my_word.chars()
                                .flat_map(transliterate)
                                .filter_map(lowercase_if_alpha);
-        let mut their_chars = other.word()
-                                   .chars()
+        let mut their_chars = their_word.chars()
                                    .flat_map(transliterate)
                                    .filter_map(lowercase_if_alpha);
         let mut matched = 0;
@@ -432,8 +628,15 @@ impl<'a> NameWordOrInitial<'a> {
                                                                   my_chars.collect::<String>()));
                 }
             } else if my_char != their_char {
-                // Failed match; abort, but first, maybe try nickname db
-                if allow_nicknames && have_matching_variants(self.word(), other.word()) {
+                // Failed match, but the words may only differ in how umlauts
+                // are spelled ("Jürgen" and "Juergen")
+                if expand_umlauts(&my_word) == expand_umlauts(&their_word) {
+                    return ComparisonResult::ExactMatch;
+                }
+
+                // Abort, but first, maybe try nickname db
+                if allow_nicknames && max_kind > MatchKind::Consistent &&
+                   given_names_match(self.word(), other.word(), options, max_kind) {
                     return ComparisonResult::NicknameMatch;
                 } else {
                     return ComparisonResult::Inconsistent;
@@ -445,23 +648,50 @@ impl<'a> NameWordOrInitial<'a> {
     }
 
     fn word(&self) -> &str {
-        match self {
-            &NameWordOrInitial::Word(word, _) => word,
-            &NameWordOrInitial::Initial(_) => unreachable!(),
+        match *self {
+            NameWordOrInitial::Word(word, _) => word,
+            NameWordOrInitial::Initial(_) => unreachable!(),
         }
     }
 
     fn has_word(&self) -> bool {
-        match self {
-            &NameWordOrInitial::Word(_, _) => true,
-            &NameWordOrInitial::Initial(_) => false,
+        match *self {
+            NameWordOrInitial::Word(_, _) => true,
+            NameWordOrInitial::Initial(_) => false,
         }
     }
 
     fn initials_count(&self) -> u8 {
-        match self {
-            &NameWordOrInitial::Word(_, count) => count as u8,
-            &NameWordOrInitial::Initial(_) => 1,
+        match *self {
+            NameWordOrInitial::Word(_, count) => count as u8,
+            NameWordOrInitial::Initial(_) => 1,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn consistent(a: &str, b: &str, options: &ComparisonOptions) -> bool {
+        let a = Name::parse(a).unwrap();
+        let b = Name::parse(b).unwrap();
+        a.consistent_with_options(&b, options)
+    }
+
+    #[test]
+    fn gendered_surnames() {
+        let options = ComparisonOptions { gendered_surnames: true, ..ComparisonOptions::default() };
+
+        assert!(consistent("Jan Novák", "J. Nováková", &options));
+        assert!(consistent("Jan Novotný", "J. Novotná", &options));
+        assert!(consistent("Ivan Petrov", "I. Petrova", &options));
+        assert!(consistent("Jan Kowalski", "J. Kowalska", &options));
+        assert!(consistent("Jonas Kazlauskas", "J. Kazlauskienė", &options));
+        assert!(!consistent("Jan Novák", "J. Nováková", &ComparisonOptions::default()));
+
+        assert!(!consistent("Jan Martin", "J. Marta", &options));
+        assert!(!consistent("Jan Davis", "J. Davy", &options));
+        assert!(!consistent("Jan Lewis", "J. Lewy", &options));
+    }
+}
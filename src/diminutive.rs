@@ -0,0 +1,262 @@
+use phf;
+use super::utils::*;
+
+/// A language whose diminutive and hypocoristic forms of given names should
+/// be recognized in comparison, in addition to the English and Spanish forms
+/// recognized by default (see `ComparisonOptions`).
+///
+/// # Examples
+///
+/// ```
+/// use human_name::{Name, ComparisonOptions, Language};
+///
+/// let options = ComparisonOptions { languages: &[Language::Dutch], ..ComparisonOptions::default() };
+/// let marietje = Name::parse("Marietje Bell").unwrap();
+/// let maria = Name::parse("Maria Bell").unwrap();
+///
+/// assert!(marietje.consistent_with_options(&maria, &options));
+/// assert!(!marietje.consistent_with(&maria));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Language {
+    /// -chen, plus common irregular forms ("Gretchen", "Hänsel", "Susi")
+    German,
+    /// -je and -tje ("Marietje", "Jantje")
+    Dutch,
+    /// -ino, -etto and -uccio, and their feminine forms ("Carlino", "Paoletto")
+    Italian,
+    /// -ek, -ka and -uś ("Janek", "Tomek", "Piotruś")
+    Polish,
+    /// -sha, -ya and -ochka, plus common irregular forms ("Sasha", "Vanya")
+    Russian,
+    /// -inho and -inha, including the -zinho form ("Pedrinho", "Joãozinho")
+    Portuguese,
+}
+
+impl Language {
+    /// The language for a locale identifier such as "de", "pt-BR" or
+    /// "nl_BE", if it's one we have rules for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use human_name::Language;
+    ///
+    /// assert_eq!(Some(Language::Portuguese), Language::from_locale("pt-BR"));
+    /// assert_eq!(Some(Language::Dutch), Language::from_locale("nl_BE"));
+    /// assert_eq!(None, Language::from_locale("fr-FR"));
+    /// ```
+    pub fn from_locale(locale: &str) -> Option<Language> {
+        let language = locale.split(['-', '_']).next().unwrap_or("");
+
+        match &*language.to_ascii_lowercase() {
+            "de" | "deu" | "ger" | "gsw" => Some(Language::German),
+            "nl" | "nld" | "dut" => Some(Language::Dutch),
+            "it" | "ita" => Some(Language::Italian),
+            "pl" | "pol" => Some(Language::Polish),
+            "ru" | "rus" => Some(Language::Russian),
+            "pt" | "por" => Some(Language::Portuguese),
+            _ => None,
+        }
+    }
+
+    fn suffixes(&self) -> &'static [&'static str] {
+        // Longer suffixes first, so that e.g. "Jantje" is stripped to "Jan",
+        // not "Jant"
+        match *self {
+            Language::German => &["chen"],
+            Language::Dutch => &["tje", "je"],
+            Language::Italian => &["uccio", "uccia", "etto", "etta", "ino", "ina"],
+            Language::Polish => &["ek", "ka", "us"],
+            Language::Russian => &["ochka", "echka", "sha", "ya"],
+            Language::Portuguese => &["zinho", "zinha", "inho", "inha"],
+        }
+    }
+}
+
+// Shortest stem we'll match against a formal name once a suffix is stripped;
+// shorter stems (e.g. "Pa" from "Pasha") match far too much, so common forms
+// like that belong in the irregular tables instead. For the same reason German
+// has no "-el" or "-i" rule: "Michael" isn't a diminutive of "Michaela".
+const MIN_STEM_LEN: usize = 3;
+
+// Takes ASCII-transliterated names, as the rest of the nickname logic does
+pub fn is_diminutive_of(nickname: &str, name: &str, languages: &[Language]) -> bool {
+    if nickname.len() <= name.len() && nickname.eq_ignore_ascii_case(name) {
+        return false;
+    }
+
+    languages.iter().any(|language| {
+        if is_irregular_diminutive_of(nickname, name, *language) {
+            return true;
+        }
+
+        let lower = nickname.to_ascii_lowercase();
+        let suffix = language.suffixes().iter().find(|suffix| {
+            lower.ends_with(*suffix) && lower.len() >= suffix.len() + MIN_STEM_LEN
+        });
+
+        match suffix {
+            Some(suffix) => {
+                let stem = &nickname[0..nickname.len() - suffix.len()];
+                if name.len() >= stem.len() && eq_or_starts_with!(stem, name) {
+                    return true;
+                }
+
+                // The stem may keep a vowel the formal name doesn't have, or
+                // has differently ("Marietje" for "Maria")
+                if stem.len() > MIN_STEM_LEN && !starts_with_consonant(&stem[stem.len() - 1..]) {
+                    let stem = &stem[0..stem.len() - 1];
+                    name.len() > stem.len() && eq_or_starts_with!(stem, name)
+                } else {
+                    false
+                }
+            }
+            None => false,
+        }
+    })
+}
+
+fn is_irregular_diminutive_of(nickname: &str, name: &str, language: Language) -> bool {
+    let table = match language {
+        Language::German => &GERMAN_NAMES_BY_IRREGULAR_DIMINUTIVE,
+        Language::Russian => &RUSSIAN_NAMES_BY_IRREGULAR_DIMINUTIVE,
+        _ => return false,
+    };
+
+    match table.get(nickname) {
+        Some(names) => names.contains(name),
+        None => false,
+    }
+}
+
+static GERMAN_NAMES_BY_IRREGULAR_DIMINUTIVE: phf::Map<&'static str, phf::Set<&'static str>> = phf_map! {
+    "Gretel" => phf_set! { "Grete", "Margarete", "Margarethe" },
+    "Hansel" => phf_set! { "Hans", "Johann", "Johannes" },
+    "Hansi" => phf_set! { "Hans", "Johann", "Johannes" },
+    "Liesel" => phf_set! { "Elisabeth", "Liese" },
+    "Susi" => phf_set! { "Susanna", "Susanne" },
+};
+
+static RUSSIAN_NAMES_BY_IRREGULAR_DIMINUTIVE: phf::Map<&'static str, phf::Set<&'static str>> = phf_map! {
+    "Alyosha" => phf_set! { "Aleksei", "Aleksey", "Alexei", "Alexey" },
+    "Borya" => phf_set! { "Boris" },
+    "Dima" => phf_set! { "Dmitri", "Dmitrii", "Dmitriy", "Dmitry" },
+    "Grisha" => phf_set! { "Grigori", "Grigoriy", "Grigory" },
+    "Katya" => phf_set! { "Ekaterina", "Yekaterina" },
+    "Kolya" => phf_set! { "Nikolai", "Nikolay" },
+    "Lena" => phf_set! { "Elena", "Yelena" },
+    "Lyosha" => phf_set! { "Aleksei", "Aleksey", "Alexei", "Alexey" },
+    "Lyuba" => phf_set! { "Lyubov" },
+    "Masha" => phf_set! { "Maria", "Mariya" },
+    "Misha" => phf_set! { "Mikhail" },
+    "Nadya" => phf_set! { "Nadezhda" },
+    "Olya" => phf_set! { "Olga" },
+    "Pasha" => phf_set! { "Pavel" },
+    "Sasha" => phf_set! { "Aleksandr", "Aleksandra", "Alexander", "Alexandr", "Alexandra" },
+    "Seryozha" => phf_set! { "Sergei", "Sergey" },
+    "Slava" => phf_set! { "Stanislav", "Vladislav", "Vyacheslav", "Yaroslav" },
+    "Sveta" => phf_set! { "Svetlana" },
+    "Tanya" => phf_set! { "Tatiana", "Tatyana" },
+    "Tolya" => phf_set! { "Anatoli", "Anatoliy", "Anatoly" },
+    "Vanya" => phf_set! { "Ivan" },
+    "Volodya" => phf_set! { "Vladimir" },
+    "Yura" => phf_set! { "Yuri", "Yuriy", "Yury" },
+    "Zhenya" => phf_set! { "Evgenia", "Evgeny", "Evgeniy", "Yevgenia", "Yevgeny", "Yevgeniy" },
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nickname::have_matching_variants_in;
+
+    fn matches(a: &str, b: &str, language: Language) -> bool {
+        have_matching_variants_in(a, b, None, &[language])
+    }
+
+    #[test]
+    fn locales() {
+        assert_eq!(Some(Language::German), Language::from_locale("de"));
+        assert_eq!(Some(Language::German), Language::from_locale("DE-at"));
+        assert_eq!(Some(Language::Russian), Language::from_locale("ru_RU"));
+        assert_eq!(Some(Language::Polish), Language::from_locale("pol"));
+        assert_eq!(None, Language::from_locale("en-US"));
+        assert_eq!(None, Language::from_locale(""));
+    }
+
+    #[test]
+    fn german() {
+        assert!(matches("Gretchen", "Grete", Language::German));
+        assert!(matches("Grete", "Gretchen", Language::German));
+        assert!(matches("Lenchen", "Lena", Language::German));
+        assert!(matches("Susi", "Susanne", Language::German));
+        assert!(matches("Hänsel", "Hans", Language::German));
+        assert!(!matches("Lenchen", "Lena", Language::Dutch));
+        assert!(!matches("Gretchen", "Susanne", Language::German));
+        assert!(!matches("Michael", "Michaela", Language::German));
+        assert!(!matches("Michaela", "Michael", Language::German));
+        assert!(!matches("Daniel", "Daniela", Language::German));
+        assert!(!matches("Daniela", "Daniel", Language::German));
+    }
+
+    #[test]
+    fn dutch() {
+        assert!(matches("Marietje", "Maria", Language::Dutch));
+        assert!(matches("Maria", "Marietje", Language::Dutch));
+        assert!(matches("Annetje", "Anna", Language::Dutch));
+        assert!(matches("Jantje", "Jan", Language::Dutch));
+        assert!(!matches("Jantje", "Piet", Language::Dutch));
+        assert!(!matches("Marietje", "Maria", Language::German));
+    }
+
+    #[test]
+    fn italian() {
+        assert!(matches("Giovannino", "Giovanni", Language::Italian));
+        assert!(matches("Carlino", "Carlo", Language::Italian));
+        assert!(matches("Paoletto", "Paolo", Language::Italian));
+        assert!(matches("Carluccio", "Carlo", Language::Italian));
+        assert!(matches("Paoletta", "Paola", Language::Italian));
+        assert!(!matches("Carlino", "Paolo", Language::Italian));
+    }
+
+    #[test]
+    fn polish() {
+        assert!(matches("Janek", "Jan", Language::Polish));
+        assert!(matches("Tomek", "Tomasz", Language::Polish));
+        assert!(matches("Piotruś", "Piotr", Language::Polish));
+        assert!(matches("Jacuś", "Jacek", Language::Polish));
+        assert!(!matches("Tomek", "Jan", Language::Polish));
+        assert!(!matches("Jacuś", "Jacek", Language::Russian));
+    }
+
+    #[test]
+    fn russian() {
+        assert!(matches("Sasha", "Alexander", Language::Russian));
+        assert!(matches("Alexander", "Sasha", Language::Russian));
+        assert!(matches("Sasha", "Aleksandra", Language::Russian));
+        assert!(matches("Vanya", "Ivan", Language::Russian));
+        assert!(matches("Natasha", "Natalia", Language::Russian));
+        assert!(matches("Verochka", "Vera", Language::Russian));
+        assert!(matches("Petya", "Petr", Language::Russian));
+        assert!(!matches("Sasha", "Ivan", Language::Russian));
+        assert!(!matches("Pasha", "Pamela", Language::Russian));
+        assert!(!matches("Vanya", "Ivan", Language::Polish));
+    }
+
+    #[test]
+    fn portuguese() {
+        assert!(matches("Pedrinho", "Pedro", Language::Portuguese));
+        assert!(matches("Paulinha", "Paula", Language::Portuguese));
+        assert!(matches("Joãozinho", "João", Language::Portuguese));
+        assert!(!matches("Pedrinho", "Paulo", Language::Portuguese));
+    }
+
+    #[test]
+    fn without_languages() {
+        assert!(!have_matching_variants_in("Vanya", "Ivan", None, &[]));
+        assert!(!have_matching_variants_in("Lenchen", "Lena", None, &[]));
+        assert!(!have_matching_variants_in("Jacuś", "Jacek", None, &[]));
+        assert!(!have_matching_variants_in("Marietje", "Maria", None, &[]));
+        assert!(!have_matching_variants_in("Carluccio", "Carlo", None, &[]));
+    }
+}
@@ -1,12 +1,59 @@
 //! A C API for interacting with `Name` objects.
+//!
+//! See `include/human_name.h` for the corresponding declarations, which are
+//! generated from this file by cbindgen (see `cbindgen.toml`).
+
+// These functions are called from C, which has no notion of `unsafe`; each
+// checks its pointers for null instead
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
 
 extern crate libc;
 
-use self::libc::c_char;
+use self::libc::{c_char, c_int, size_t};
+use std::cmp;
+use std::collections::HashMap;
 use std::ffi::{CString, CStr};
 use std::mem;
 use std::ptr;
-use super::Name;
+use std::slice;
+use super::{Name, ParseOptions, MAX_INPUT_LEN};
+
+/// Version of the C API, incremented whenever functions or fields are added
+pub const HUMAN_NAME_API_VERSION: u32 = 4;
+
+pub const HUMAN_NAME_OK: c_int = 0;
+pub const HUMAN_NAME_ERROR_NULL_INPUT: c_int = 1;
+pub const HUMAN_NAME_ERROR_INVALID_UTF8: c_int = 2;
+pub const HUMAN_NAME_ERROR_TOO_LONG: c_int = 3;
+pub const HUMAN_NAME_ERROR_NO_LETTERS: c_int = 4;
+pub const HUMAN_NAME_ERROR_UNPARSEABLE: c_int = 5;
+pub const HUMAN_NAME_ERROR_UNSUPPORTED_VERSION: c_int = 6;
+pub const HUMAN_NAME_ERROR_BUFFER_TOO_SMALL: c_int = 7;
+pub const HUMAN_NAME_NOT_PRESENT: c_int = 8;
+pub const HUMAN_NAME_ERROR_OVERFLOW: c_int = 9;
+
+/// Options for `human_name_parse_ex`.
+///
+/// Callers must set `version` to the `HUMAN_NAME_API_VERSION` they were
+/// compiled against, so that fields can be added in future versions without
+/// breaking compatibility.
+#[repr(C)]
+pub struct HumanNameParseOptions {
+    pub version: u32,
+    /// Keep titles and nicknames (see `human_name_honorific_prefix`, etc)
+    pub preserve_extras: bool,
+}
+
+/// Output of `human_name_parse_batch`, as parallel arrays allocated by the
+/// caller, each with room for one entry per input. Any of the arrays may be
+/// null if the caller doesn't need it, e.g. `names` when only the hashes
+/// are wanted.
+#[repr(C)]
+pub struct HumanNameBatch {
+    pub names: *mut *mut Name,
+    pub errors: *mut c_int,
+    pub hashes: *mut u64,
+}
 
 macro_rules! str_to_char_star {
     ($str:expr) => { {
@@ -22,15 +69,283 @@ macro_rules! option_str_to_char_star {
                 let s = CString::new(string).unwrap();
                 s.into_raw()
             }
-            None => ptr::null()
+            None => ptr::null_mut()
         }
     }
 }
 
+/// Parses a name, returning null if the input can't be parsed.
 #[no_mangle]
 pub extern "C" fn human_name_parse(input: *const libc::c_char) -> Option<Box<Name>> {
     let s = unsafe { CStr::from_ptr(input).to_string_lossy() };
-    Name::parse(&*s).map(|n| Box::new(n))
+    Name::parse(&*s).map(Box::new)
+}
+
+/// Version of the library's C API, to compare with `HUMAN_NAME_API_VERSION`
+#[no_mangle]
+pub extern "C" fn human_name_api_version() -> u32 {
+    HUMAN_NAME_API_VERSION
+}
+
+/// Like `human_name_parse`, but accepts options (which may be null, for the
+/// defaults) and reports the reason for any failure via `error` (which may
+/// also be null).
+#[no_mangle]
+pub extern "C" fn human_name_parse_ex(input: *const libc::c_char,
+                                      options: *const HumanNameParseOptions,
+                                      error: *mut c_int)
+                                      -> Option<Box<Name>> {
+    let result = parse_ex(input, options);
+
+    if !error.is_null() {
+        unsafe {
+            *error = match result {
+                Ok(_) => HUMAN_NAME_OK,
+                Err(code) => code,
+            };
+        }
+    }
+
+    result.ok().map(Box::new)
+}
+
+fn parse_ex(input: *const libc::c_char,
+            options: *const HumanNameParseOptions)
+            -> Result<Name, c_int> {
+    if input.is_null() {
+        return Err(HUMAN_NAME_ERROR_NULL_INPUT);
+    }
+
+    let options = if options.is_null() {
+        ParseOptions::default()
+    } else {
+        let options = unsafe { &*options };
+        if options.version == 0 || options.version > HUMAN_NAME_API_VERSION {
+            return Err(HUMAN_NAME_ERROR_UNSUPPORTED_VERSION);
+        }
+
+        ParseOptions { preserve_extras: options.preserve_extras, ..ParseOptions::default() }
+    };
+
+    let s = match unsafe { CStr::from_ptr(input).to_str() } {
+        Ok(s) => s,
+        Err(_) => return Err(HUMAN_NAME_ERROR_INVALID_UTF8),
+    };
+
+    if s.len() >= MAX_INPUT_LEN {
+        Err(HUMAN_NAME_ERROR_TOO_LONG)
+    } else if !s.chars().any(char::is_alphabetic) {
+        Err(HUMAN_NAME_ERROR_NO_LETTERS)
+    } else {
+        Name::parse_with_options(s, &options).ok_or(HUMAN_NAME_ERROR_UNPARSEABLE)
+    }
+}
+
+/// Parses `n` inputs, filling in the caller's arrays in `out` (see
+/// `HumanNameBatch`). Failed entries get a null name, an error code as from
+/// `human_name_parse_ex`, and a hash of zero. Returns the number of inputs
+/// successfully parsed. Each name must be freed, e.g. by passing the names
+/// array to `human_name_free_batch`.
+#[no_mangle]
+pub extern "C" fn human_name_parse_batch(inputs: *const *const c_char,
+                                         n: size_t,
+                                         options: *const HumanNameParseOptions,
+                                         out: *const HumanNameBatch)
+                                         -> size_t {
+    if inputs.is_null() || out.is_null() || n == 0 {
+        return 0;
+    }
+
+    let inputs = unsafe { slice::from_raw_parts(inputs, n) };
+    let out = unsafe { &*out };
+    let mut parsed = 0;
+
+    for (i, &input) in inputs.iter().enumerate() {
+        let result = parse_ex(input, options);
+
+        unsafe {
+            if !out.errors.is_null() {
+                *out.errors.add(i) = match result {
+                    Ok(_) => HUMAN_NAME_OK,
+                    Err(code) => code,
+                };
+            }
+
+            if !out.hashes.is_null() {
+                *out.hashes.add(i) = match result {
+                    Ok(ref name) => name.memoized_surname_hash(),
+                    Err(_) => 0,
+                };
+            }
+
+            if !out.names.is_null() {
+                *out.names.add(i) = match result {
+                    Ok(name) => {
+                        parsed += 1;
+                        Box::into_raw(Box::new(name))
+                    }
+                    Err(_) => ptr::null_mut(),
+                };
+            } else if result.is_ok() {
+                parsed += 1;
+            }
+        }
+    }
+
+    parsed
+}
+
+/// Frees each non-null name in an array of `n` names (but not the array
+/// itself), e.g. as filled in by `human_name_parse_batch`.
+#[no_mangle]
+pub extern "C" fn human_name_free_batch(names: *mut *mut Name, n: size_t) {
+    if names.is_null() {
+        return;
+    }
+
+    let names = unsafe { slice::from_raw_parts(names, n) };
+    for &name in names.iter() {
+        if !name.is_null() {
+            human_name_free_name(name);
+        }
+    }
+}
+
+/// For each of `n_a` names in `a` and `n_b` names in `b`, writes whether
+/// they are consistent to `out`, which must have room for `n_a * n_b`
+/// entries, in row-major order (i.e. `out[i * n_b + j]` compares `a[i]` to
+/// `b[j]`). Null names are consistent with nothing.
+///
+/// Returns `HUMAN_NAME_OK`, or, without writing anything,
+/// `HUMAN_NAME_ERROR_OVERFLOW` if `n_a * n_b` overflows or
+/// `HUMAN_NAME_ERROR_NULL_INPUT` if any array is null when there are entries
+/// to compare.
+#[no_mangle]
+pub extern "C" fn human_name_match_matrix(a: *const *const Name,
+                                          n_a: size_t,
+                                          b: *const *const Name,
+                                          n_b: size_t,
+                                          out: *mut bool)
+                                          -> c_int {
+    let n_out = match n_a.checked_mul(n_b) {
+        Some(n) => n,
+        None => return HUMAN_NAME_ERROR_OVERFLOW,
+    };
+
+    if n_out == 0 {
+        return HUMAN_NAME_OK;
+    }
+
+    if a.is_null() || b.is_null() || out.is_null() {
+        return HUMAN_NAME_ERROR_NULL_INPUT;
+    }
+
+    let a = unsafe { slice::from_raw_parts(a, n_a) };
+    let b = unsafe { slice::from_raw_parts(b, n_b) };
+    let out = unsafe { slice::from_raw_parts_mut(out, n_out) };
+
+    for (i, &name_a) in a.iter().enumerate() {
+        for (j, &name_b) in b.iter().enumerate() {
+            out[i * n_b + j] = !name_a.is_null() && !name_b.is_null() &&
+                               unsafe { (*name_a).consistent_with(&*name_b) };
+        }
+    }
+
+    HUMAN_NAME_OK
+}
+
+/// Groups `n` names into clusters of possibly-identical names, writing a
+/// cluster id for each name to `out_cluster_ids`, which must have room for
+/// `n` entries. Cluster ids are numbered from zero in order of first
+/// appearance. Returns the number of clusters.
+///
+/// Because consistency isn't transitive, clusters are connected components:
+/// "J. Doe" will put "Jane Doe" and "John Doe" in the same cluster, even
+/// though they aren't consistent with each other. Null names each get a
+/// cluster of their own.
+#[no_mangle]
+pub extern "C" fn human_name_cluster(names: *const *const Name,
+                                     n: size_t,
+                                     out_cluster_ids: *mut size_t)
+                                     -> size_t {
+    if names.is_null() || out_cluster_ids.is_null() || n == 0 {
+        return 0;
+    }
+
+    let names = unsafe { slice::from_raw_parts(names, n) };
+    let out = unsafe { slice::from_raw_parts_mut(out_cluster_ids, n) };
+
+    // Only names with equal hashes can be consistent, so we only need to
+    // compare within buckets
+    let mut buckets: HashMap<u64, Vec<usize>> = HashMap::new();
+    for (i, &name) in names.iter().enumerate() {
+        if !name.is_null() {
+            let hash = unsafe { (*name).memoized_surname_hash() };
+            buckets.entry(hash).or_default().push(i);
+        }
+    }
+
+    let mut parents: Vec<usize> = (0..n).collect();
+    for bucket in buckets.values() {
+        for (k, &i) in bucket.iter().enumerate() {
+            for &j in bucket[k + 1..].iter() {
+                if unsafe { (*names[i]).consistent_with(&*names[j]) } {
+                    let root_i = find_root(&mut parents, i);
+                    let root_j = find_root(&mut parents, j);
+                    if root_i != root_j {
+                        parents[cmp::max(root_i, root_j)] = cmp::min(root_i, root_j);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut cluster_ids: HashMap<usize, usize> = HashMap::new();
+    for (i, cluster_id) in out.iter_mut().enumerate() {
+        let root = find_root(&mut parents, i);
+        let next_id = cluster_ids.len();
+        *cluster_id = *cluster_ids.entry(root).or_insert(next_id);
+    }
+
+    cluster_ids.len()
+}
+
+fn find_root(parents: &mut [usize], i: usize) -> usize {
+    let mut root = i;
+    while parents[root] != root {
+        root = parents[root];
+    }
+
+    // Compress the path for subsequent lookups
+    let mut j = i;
+    while parents[j] != root {
+        let next = parents[j];
+        parents[j] = root;
+        j = next;
+    }
+
+    root
+}
+
+/// Returns a static description of an error code from `human_name_parse_ex`,
+/// which must not be freed.
+#[no_mangle]
+pub extern "C" fn human_name_error_message(error: c_int) -> *const c_char {
+    let message: &'static [u8] = match error {
+        HUMAN_NAME_OK => b"no error\0",
+        HUMAN_NAME_ERROR_NULL_INPUT => b"input was null\0",
+        HUMAN_NAME_ERROR_INVALID_UTF8 => b"input was not valid UTF-8\0",
+        HUMAN_NAME_ERROR_TOO_LONG => b"input was too long\0",
+        HUMAN_NAME_ERROR_NO_LETTERS => b"input contained no letters\0",
+        HUMAN_NAME_ERROR_UNPARSEABLE => b"input could not be parsed as a name\0",
+        HUMAN_NAME_ERROR_UNSUPPORTED_VERSION => b"unsupported options version\0",
+        HUMAN_NAME_ERROR_BUFFER_TOO_SMALL => b"buffer was too small\0",
+        HUMAN_NAME_NOT_PRESENT => b"component was not present\0",
+        HUMAN_NAME_ERROR_OVERFLOW => b"array size overflowed\0",
+        _ => b"unknown error\0",
+    };
+
+    message.as_ptr() as *const c_char
 }
 
 #[no_mangle]
@@ -58,27 +373,27 @@ pub extern "C" fn human_name_hash(name: &Name) -> u64 {
 }
 
 #[no_mangle]
-pub extern "C" fn human_name_surname(name: &Name) -> *const c_char {
+pub extern "C" fn human_name_surname(name: &Name) -> *mut c_char {
     str_to_char_star!(name.surname().into_owned())
 }
 
 #[no_mangle]
-pub extern "C" fn human_name_given_name(name: &Name) -> *const c_char {
+pub extern "C" fn human_name_given_name(name: &Name) -> *mut c_char {
     option_str_to_char_star!(name.given_name())
 }
 
 #[no_mangle]
-pub extern "C" fn human_name_initials(name: &Name) -> *const c_char {
+pub extern "C" fn human_name_initials(name: &Name) -> *mut c_char {
     str_to_char_star!(name.initials())
 }
 
 #[no_mangle]
-pub extern "C" fn human_name_first_initial(name: &Name) -> *const c_char {
+pub extern "C" fn human_name_first_initial(name: &Name) -> *mut c_char {
     str_to_char_star!(name.first_initial().to_string())
 }
 
 #[no_mangle]
-pub extern "C" fn human_name_middle_initials(name: &Name) -> *const c_char {
+pub extern "C" fn human_name_middle_initials(name: &Name) -> *mut c_char {
     option_str_to_char_star!(name.middle_initials())
 }
 
@@ -96,27 +411,27 @@ pub extern "C" fn human_name_matches_slug_or_localpart(name: &Name,
 }
 
 #[no_mangle]
-pub extern "C" fn human_name_middle_names(name: &Name) -> *const c_char {
+pub extern "C" fn human_name_middle_names(name: &Name) -> *mut c_char {
     option_str_to_char_star!(name.middle_name().map(|n| n.into_owned()))
 }
 
 #[no_mangle]
-pub extern "C" fn human_name_suffix(name: &Name) -> *const c_char {
+pub extern "C" fn human_name_suffix(name: &Name) -> *mut c_char {
     option_str_to_char_star!(name.suffix())
 }
 
 #[no_mangle]
-pub extern "C" fn human_name_display_first_last(name: &Name) -> *const c_char {
+pub extern "C" fn human_name_display_first_last(name: &Name) -> *mut c_char {
     str_to_char_star!(name.display_first_last())
 }
 
 #[no_mangle]
-pub extern "C" fn human_name_display_initial_surname(name: &Name) -> *const c_char {
+pub extern "C" fn human_name_display_initial_surname(name: &Name) -> *mut c_char {
     str_to_char_star!(name.display_initial_surname())
 }
 
 #[no_mangle]
-pub extern "C" fn human_name_display_full(name: &Name) -> *const c_char {
+pub extern "C" fn human_name_display_full(name: &Name) -> *mut c_char {
     str_to_char_star!(name.display_full())
 }
 
@@ -124,3 +439,230 @@ pub extern "C" fn human_name_display_full(name: &Name) -> *const c_char {
 pub extern "C" fn human_name_byte_len(name: &Name) -> u32 {
     name.byte_len() as u32
 }
+
+/// Only present if parsed with `preserve_extras`
+#[no_mangle]
+pub extern "C" fn human_name_honorific_prefix(name: &Name) -> *mut c_char {
+    option_str_to_char_star!(name.honorific_prefix())
+}
+
+/// Only present if parsed with `preserve_extras`
+#[no_mangle]
+pub extern "C" fn human_name_honorific_suffix(name: &Name) -> *mut c_char {
+    option_str_to_char_star!(name.honorific_suffix())
+}
+
+/// Only present if parsed with `preserve_extras`
+#[no_mangle]
+pub extern "C" fn human_name_nickname(name: &Name) -> *mut c_char {
+    option_str_to_char_star!(name.nickname())
+}
+
+// Copies `s` plus a terminating NUL into the caller's buffer if there's room,
+// and always sets `out_len` (if non-null) to the length of `s` in bytes,
+// excluding the NUL, so callers can retry with a larger buffer.
+fn write_to_buffer(s: Option<&str>,
+                   buf: *mut c_char,
+                   buf_len: size_t,
+                   out_len: *mut size_t)
+                   -> c_int {
+    let s = match s {
+        Some(s) => s,
+        None => {
+            if !out_len.is_null() {
+                unsafe {
+                    *out_len = 0;
+                }
+            }
+            return HUMAN_NAME_NOT_PRESENT;
+        }
+    };
+
+    if !out_len.is_null() {
+        unsafe {
+            *out_len = s.len();
+        }
+    }
+
+    if buf.is_null() || buf_len < s.len() + 1 {
+        return HUMAN_NAME_ERROR_BUFFER_TOO_SMALL;
+    }
+
+    unsafe {
+        ptr::copy_nonoverlapping(s.as_ptr() as *const c_char, buf, s.len());
+        *buf.add(s.len()) = 0;
+    }
+
+    HUMAN_NAME_OK
+}
+
+// Returns a pointer into memory owned by the name (or static memory), which
+// is _not_ NUL-terminated, and sets `out_len` to its length in bytes. Returns
+// null, with a length of zero, if the component is absent.
+fn borrow_str(s: Option<&str>, out_len: *mut size_t) -> *const c_char {
+    if !out_len.is_null() {
+        unsafe {
+            *out_len = s.map_or(0, |s| s.len());
+        }
+    }
+
+    s.map_or(ptr::null(), |s| s.as_ptr() as *const c_char)
+}
+
+#[no_mangle]
+pub extern "C" fn human_name_surname_buf(name: &Name,
+                                         buf: *mut c_char,
+                                         buf_len: size_t,
+                                         out_len: *mut size_t)
+                                         -> c_int {
+    write_to_buffer(Some(&*name.surname()), buf, buf_len, out_len)
+}
+
+#[no_mangle]
+pub extern "C" fn human_name_given_name_buf(name: &Name,
+                                            buf: *mut c_char,
+                                            buf_len: size_t,
+                                            out_len: *mut size_t)
+                                            -> c_int {
+    write_to_buffer(name.given_name(), buf, buf_len, out_len)
+}
+
+#[no_mangle]
+pub extern "C" fn human_name_initials_buf(name: &Name,
+                                          buf: *mut c_char,
+                                          buf_len: size_t,
+                                          out_len: *mut size_t)
+                                          -> c_int {
+    write_to_buffer(Some(name.initials()), buf, buf_len, out_len)
+}
+
+#[no_mangle]
+pub extern "C" fn human_name_first_initial_buf(name: &Name,
+                                               buf: *mut c_char,
+                                               buf_len: size_t,
+                                               out_len: *mut size_t)
+                                               -> c_int {
+    write_to_buffer(Some(&*name.first_initial().to_string()), buf, buf_len, out_len)
+}
+
+#[no_mangle]
+pub extern "C" fn human_name_middle_initials_buf(name: &Name,
+                                                 buf: *mut c_char,
+                                                 buf_len: size_t,
+                                                 out_len: *mut size_t)
+                                                 -> c_int {
+    write_to_buffer(name.middle_initials(), buf, buf_len, out_len)
+}
+
+#[no_mangle]
+pub extern "C" fn human_name_middle_names_buf(name: &Name,
+                                              buf: *mut c_char,
+                                              buf_len: size_t,
+                                              out_len: *mut size_t)
+                                              -> c_int {
+    write_to_buffer(name.middle_name().as_deref(), buf, buf_len, out_len)
+}
+
+#[no_mangle]
+pub extern "C" fn human_name_suffix_buf(name: &Name,
+                                        buf: *mut c_char,
+                                        buf_len: size_t,
+                                        out_len: *mut size_t)
+                                        -> c_int {
+    write_to_buffer(name.suffix(), buf, buf_len, out_len)
+}
+
+#[no_mangle]
+pub extern "C" fn human_name_display_first_last_buf(name: &Name,
+                                                    buf: *mut c_char,
+                                                    buf_len: size_t,
+                                                    out_len: *mut size_t)
+                                                    -> c_int {
+    write_to_buffer(Some(&*name.display_first_last()), buf, buf_len, out_len)
+}
+
+#[no_mangle]
+pub extern "C" fn human_name_display_initial_surname_buf(name: &Name,
+                                                         buf: *mut c_char,
+                                                         buf_len: size_t,
+                                                         out_len: *mut size_t)
+                                                         -> c_int {
+    write_to_buffer(Some(&*name.display_initial_surname()), buf, buf_len, out_len)
+}
+
+#[no_mangle]
+pub extern "C" fn human_name_display_full_buf(name: &Name,
+                                              buf: *mut c_char,
+                                              buf_len: size_t,
+                                              out_len: *mut size_t)
+                                              -> c_int {
+    write_to_buffer(Some(&*name.display_full()), buf, buf_len, out_len)
+}
+
+#[no_mangle]
+pub extern "C" fn human_name_honorific_prefix_buf(name: &Name,
+                                                  buf: *mut c_char,
+                                                  buf_len: size_t,
+                                                  out_len: *mut size_t)
+                                                  -> c_int {
+    write_to_buffer(name.honorific_prefix(), buf, buf_len, out_len)
+}
+
+#[no_mangle]
+pub extern "C" fn human_name_honorific_suffix_buf(name: &Name,
+                                                  buf: *mut c_char,
+                                                  buf_len: size_t,
+                                                  out_len: *mut size_t)
+                                                  -> c_int {
+    write_to_buffer(name.honorific_suffix(), buf, buf_len, out_len)
+}
+
+#[no_mangle]
+pub extern "C" fn human_name_nickname_buf(name: &Name,
+                                          buf: *mut c_char,
+                                          buf_len: size_t,
+                                          out_len: *mut size_t)
+                                          -> c_int {
+    write_to_buffer(name.nickname(), buf, buf_len, out_len)
+}
+
+#[no_mangle]
+pub extern "C" fn human_name_given_name_ref(name: &Name, out_len: *mut size_t) -> *const c_char {
+    borrow_str(name.given_name(), out_len)
+}
+
+#[no_mangle]
+pub extern "C" fn human_name_initials_ref(name: &Name, out_len: *mut size_t) -> *const c_char {
+    borrow_str(Some(name.initials()), out_len)
+}
+
+#[no_mangle]
+pub extern "C" fn human_name_middle_initials_ref(name: &Name,
+                                                 out_len: *mut size_t)
+                                                 -> *const c_char {
+    borrow_str(name.middle_initials(), out_len)
+}
+
+#[no_mangle]
+pub extern "C" fn human_name_suffix_ref(name: &Name, out_len: *mut size_t) -> *const c_char {
+    borrow_str(name.suffix(), out_len)
+}
+
+#[no_mangle]
+pub extern "C" fn human_name_honorific_prefix_ref(name: &Name,
+                                                  out_len: *mut size_t)
+                                                  -> *const c_char {
+    borrow_str(name.honorific_prefix(), out_len)
+}
+
+#[no_mangle]
+pub extern "C" fn human_name_honorific_suffix_ref(name: &Name,
+                                                  out_len: *mut size_t)
+                                                  -> *const c_char {
+    borrow_str(name.honorific_suffix(), out_len)
+}
+
+#[no_mangle]
+pub extern "C" fn human_name_nickname_ref(name: &Name, out_len: *mut size_t) -> *const c_char {
+    borrow_str(name.nickname(), out_len)
+}
@@ -0,0 +1,259 @@
+use std::ops::Range;
+use super::Name;
+use super::title;
+use super::suffix;
+use super::surname;
+use super::utils::is_nonalphanumeric;
+use super::namepart::{NamePart, Location};
+
+// Longest run of words we'll consider as one name
+const MAX_NAME_WORDS: usize = 8;
+
+// Capitalized words which often directly precede a name in running text,
+// e.g. at the start of a sentence or in a byline or signature
+static LEADING_NON_NAMES: [&'static str; 24] = ["A", "An", "The", "By", "From", "To", "With",
+                                                "And", "Or", "In", "On", "Of", "Dear", "Hi",
+                                                "Hello", "Thanks", "Regards", "Sincerely",
+                                                "Cheers", "Best", "Written", "Posted",
+                                                "Edited", "Contact"];
+
+/// A name found in running text by `extract`.
+pub struct ExtractedName {
+    /// Byte range of the name in the input, including any titles or suffixes
+    pub span: Range<usize>,
+    /// The parsed name
+    pub name: Name,
+}
+
+struct Token<'a> {
+    word: &'a str,
+    begin: usize,
+    end: usize,
+    comma_after: bool,
+    breaks_after: bool,
+}
+
+/// Finds likely names in running text, such as bylines, email signatures and
+/// author lists.
+///
+/// Looks for runs of capitalized words and initials (allowing lowercase
+/// surname particles like "de" or "van" mid-run), strips any leading titles,
+/// extends the match over comma-separated suffixes and degrees, and keeps
+/// runs which `Name::parse` accepts.
+///
+/// This is a heuristic, which will miss names written in lowercase and find
+/// some capitalized phrases which aren't names.
+///
+/// # Examples
+///
+/// ```
+/// use human_name::extract;
+///
+/// let text = "Written by Dr. Jane A. Doe, PhD and John Smith.";
+/// let found = extract(text);
+///
+/// assert_eq!(2, found.len());
+/// assert_eq!("Dr. Jane A. Doe, PhD", &text[found[0].span.clone()]);
+/// assert_eq!("Doe", found[0].name.surname());
+/// assert_eq!("John Smith", &text[found[1].span.clone()]);
+/// ```
+pub fn extract(text: &str) -> Vec<ExtractedName> {
+    let tokens = tokenize(text);
+    let mut result = Vec::new();
+
+    let mut i = 0;
+    while i < tokens.len() {
+        if !is_name_token(&tokens[i]) {
+            i += 1;
+            continue;
+        }
+
+        // Find the longest run of plausible name words
+        let mut begin = i;
+        let mut end = i + 1;
+        while end < tokens.len() && !tokens[end - 1].breaks_after && end - begin < MAX_NAME_WORDS {
+            if is_name_token(&tokens[end]) {
+                end += 1;
+            } else if end + 1 < tokens.len() && is_particle_token(&tokens[end]) &&
+                      !tokens[end].breaks_after &&
+                      is_name_token(&tokens[end + 1]) {
+                end += 2;
+            } else {
+                break;
+            }
+        }
+
+        while begin < end && LEADING_NON_NAMES.contains(&tokens[begin].word) {
+            begin += 1;
+        }
+
+        // Strip any prefix title, e.g. "Dr." or "Right Hon.", although we
+        // still include it in the span
+        let title_begin = begin;
+        if end - begin > 2 {
+            let mut words: Vec<NamePart> =
+                tokens[begin..end]
+                    .iter()
+                    .map(|t| NamePart::from_word(t.word, true, Location::Middle))
+                    .collect();
+            if let Some(prefix) = title::strip_prefix_title(&mut words) {
+                begin += prefix.len();
+            }
+        }
+
+        // Extend over comma-separated suffixes and degrees, e.g. ", Jr." or ", PhD"
+        let mut span_end = name_end(&tokens[end - 1]);
+        while end < tokens.len() && tokens[end - 1].comma_after && is_postfix_token(&tokens[end]) {
+            span_end = tokens[end].end;
+            end += 1;
+        }
+
+        if end - begin >= 2 {
+            let span_begin = tokens[begin].begin;
+            if let Some(name) = Name::parse(&text[span_begin..span_end]) {
+                result.push(ExtractedName {
+                    span: tokens[title_begin].begin..span_end,
+                    name: name,
+                });
+            }
+        }
+
+        i = end;
+    }
+
+    result
+}
+
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut word_begin: Option<usize> = None;
+
+    for (i, c) in text.char_indices().chain(Some((text.len(), ' '))) {
+        if !c.is_whitespace() {
+            if word_begin.is_none() {
+                word_begin = Some(i);
+            }
+            continue;
+        }
+
+        if let Some(begin) = word_begin.take() {
+            let raw = &text[begin..i];
+            let leading = raw.len() - raw.trim_start_matches(is_leading_punctuation).len();
+            let word = raw[leading..].trim_end_matches(is_trailing_punctuation);
+            let trailing = &raw[leading + word.len()..];
+
+            if !word.is_empty() {
+                tokens.push(Token {
+                    word: word,
+                    begin: begin + leading,
+                    end: begin + leading + word.len(),
+                    comma_after: trailing.starts_with(','),
+                    breaks_after: !trailing.is_empty(),
+                });
+            }
+        }
+
+        // Line breaks separate names in signatures and author lists
+        if c == '\n' {
+            if let Some(token) = tokens.last_mut() {
+                token.breaks_after = true;
+            }
+        }
+    }
+
+    tokens
+}
+
+fn is_leading_punctuation(c: char) -> bool {
+    is_nonalphanumeric(c) && c != '&'
+}
+
+fn is_trailing_punctuation(c: char) -> bool {
+    is_nonalphanumeric(c) && c != '.'
+}
+
+fn is_name_token(token: &Token) -> bool {
+    match token.word.chars().find(|c| c.is_alphabetic()) {
+        Some(c) if c.is_uppercase() => {}
+        _ => return false,
+    }
+
+    let part = NamePart::from_word(token.word, true, Location::Middle);
+    part.is_namelike() || part.is_initials() || part.is_abbreviation()
+}
+
+fn is_particle_token(token: &Token) -> bool {
+    token.word.chars().all(|c| c.is_lowercase()) && surname::is_particle(token.word)
+}
+
+fn is_postfix_token(token: &Token) -> bool {
+    let part = NamePart::from_word(token.word, true, Location::End);
+    if suffix::generation_from_suffix(&part, false).is_some() {
+        return true;
+    }
+
+    let letters: String = token.word.chars().filter(|c| c.is_alphabetic()).collect();
+    title::is_handle_title(&letters)
+}
+
+// Excludes a sentence-ending period, but not one ending an initial or suffix
+fn name_end(token: &Token) -> usize {
+    let without_period = token.word.trim_end_matches('.');
+    let letters = without_period.chars().filter(|c| c.is_alphabetic()).count();
+    if without_period.len() < token.word.len() && letters > 1 && !without_period.contains('.') &&
+       !is_postfix_token(token) {
+        token.begin + without_period.len()
+    } else {
+        token.end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extracted(text: &str) -> Vec<&str> {
+        extract(text).into_iter().map(|found| &text[found.span]).collect()
+    }
+
+    #[test]
+    fn byline() {
+        assert_eq!(vec!["Dr. Jane A. Doe, PhD"],
+                   extracted("Written by Dr. Jane A. Doe, PhD"));
+    }
+
+    #[test]
+    fn sentence_start() {
+        assert_eq!(vec!["Jane Doe"], extracted("By Jane Doe."));
+    }
+
+    #[test]
+    fn particles() {
+        assert_eq!(vec!["Ludwig van Beethoven"],
+                   extracted("a sonata by Ludwig van Beethoven, performed"));
+    }
+
+    #[test]
+    fn suffix() {
+        assert_eq!(vec!["John Smith, Jr."], extracted("signed John Smith, Jr. yesterday"));
+    }
+
+    #[test]
+    fn signature() {
+        let text = "Thanks,\nJane Doe\nAcme\n555-1234";
+        assert_eq!(vec!["Jane Doe"], extracted(text));
+    }
+
+    #[test]
+    fn author_list() {
+        assert_eq!(vec!["J. Smith", "A. B. Jones", "Carol White"],
+                   extracted("Authors: J. Smith; A. B. Jones; Carol White"));
+    }
+
+    #[test]
+    fn no_names() {
+        assert!(extract("nothing to see here, move along.").is_empty());
+        assert!(extract("Hello").is_empty());
+        assert!(extract("").is_empty());
+    }
+}
@@ -0,0 +1,303 @@
+use std::borrow::Cow;
+use super::{Name, NameWordOrInitial};
+use super::sorting::{leading_particle_count, SortConvention};
+
+/// Bibliographic citation styles supported by `Name::format_citation`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CitationStyle {
+    /// APA, e.g. "de la Hoya, O. J."
+    Apa,
+    /// MLA, e.g. "de la Hoya, Oscar J."
+    Mla,
+    /// Vancouver (ICMJE), e.g. "de la Hoya OJ"
+    Vancouver,
+    /// French administrative style, surname first in capitals, e.g.
+    /// "DE LA HOYA Oscar J."
+    FrenchOfficial,
+    /// Dutch style, with surname particles after the given names, e.g.
+    /// "Berg, Jan van den"
+    Dutch,
+}
+
+impl CitationStyle {
+    /// The style names are usually given in for a locale identifier such as
+    /// "fr", "nl-BE" or "fr_CA", if it has one that differs from how names
+    /// are cited in English. Choose between the English styles (APA, MLA,
+    /// Vancouver) by the publication, rather than by locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use human_name::{Name, CitationStyle};
+    ///
+    /// let name = Name::parse("Jan van den Berg").unwrap();
+    /// let style = CitationStyle::from_locale("nl-NL").unwrap();
+    /// assert_eq!("Berg, Jan van den", name.format_citation(style));
+    ///
+    /// let style = CitationStyle::from_locale("fr_CA").unwrap();
+    /// assert_eq!("VAN DEN BERG Jan", name.format_citation(style));
+    ///
+    /// assert_eq!(None, CitationStyle::from_locale("en-US"));
+    /// ```
+    pub fn from_locale(locale: &str) -> Option<CitationStyle> {
+        let language = locale.split(['-', '_']).next().unwrap_or("");
+
+        match &*language.to_ascii_lowercase() {
+            "fr" | "fra" | "fre" => Some(CitationStyle::FrenchOfficial),
+            "nl" | "nld" | "dut" => Some(CitationStyle::Dutch),
+            _ => None,
+        }
+    }
+
+    fn pattern(&self) -> &'static str {
+        match *self {
+            CitationStyle::Apa => "{surname}, {initials}, {suffix}",
+            CitationStyle::Mla => "{surname}, {given_names}, {suffix}",
+            CitationStyle::Vancouver => "{surname} {initials_compact} {suffix_compact}",
+            CitationStyle::FrenchOfficial => "{SURNAME} {given_names} {suffix}",
+            CitationStyle::Dutch => "{principal_surname}, {given_names} {particles}, {suffix}",
+        }
+    }
+}
+
+impl Name {
+
+    /// Formats the name according to a pattern containing any of the
+    /// following placeholders (use `{{` and `}}` for literal braces):
+    ///
+    /// * `{given}`: the given name, or first initial if it's unknown ("John")
+    /// * `{given_names}`: given and middle names or initials ("John Allen Q.")
+    /// * `{middle}`: middle names or initials ("Allen Q.")
+    /// * `{first_initial}`: "J."
+    /// * `{initials}`: first and middle initials ("J. A. Q.")
+    /// * `{middle_initials}`: "A. Q."
+    /// * `{initials_compact}`: initials without punctuation ("JAQ")
+    /// * `{surname}`: the surname, including particles ("de la MacDonald")
+    /// * `{Surname}`: the surname capitalized, e.g. at the start of a sentence
+    ///   ("De la MacDonald")
+    /// * `{SURNAME}`: the surname in capitals ("DE LA MACDONALD")
+    /// * `{particles}`: any leading surname particles, as Dutch usage sets
+    ///   them apart ("de la")
+    /// * `{principal_surname}`: the surname without those particles
+    ///   ("MacDonald")
+    /// * `{suffix}`: the generational suffix ("Jr.")
+    /// * `{suffix_compact}`: the suffix without punctuation ("Jr")
+    ///
+    /// Placeholders for parts of the name which are absent are replaced with
+    /// nothing, and any whitespace or commas left dangling as a result are
+    /// removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use human_name::Name;
+    ///
+    /// let name = Name::parse("JOHN ALLEN Q DE LA MACDONALD JR").unwrap();
+    /// assert_eq!("de la MacDonald, John A. Q.",
+    ///            name.format("{surname}, {given} {middle_initials}"));
+    /// assert_eq!("De la MacDonald (J.)", name.format("{Surname} ({first_initial})"));
+    ///
+    /// let name = Name::parse("Doe, J.").unwrap();
+    /// assert_eq!("Doe, J.", name.format("{surname}, {given} {middle_initials}, {suffix}"));
+    /// ```
+    pub fn format(&self, pattern: &str) -> String {
+        let mut result = String::with_capacity(pattern.len() + self.byte_len());
+        let mut rest = pattern;
+
+        while let Some(i) = rest.find(['{', '}']) {
+            result.push_str(&rest[..i]);
+            let remaining = &rest[i..];
+
+            if remaining.starts_with("{{") || remaining.starts_with("}}") {
+                result.push_str(&remaining[..1]);
+                rest = &remaining[2..];
+            } else if let (true, Some(end)) = (remaining.starts_with('{'), remaining.find('}')) {
+                match self.placeholder(&remaining[1..end]) {
+                    Some(value) => result.push_str(&value),
+                    None => result.push_str(&remaining[..end + 1]),
+                }
+                rest = &remaining[end + 1..];
+            } else {
+                result.push_str(&remaining[..1]);
+                rest = &remaining[1..];
+            }
+        }
+        result.push_str(rest);
+
+        tidy(&result)
+    }
+
+    /// Formats the name for a bibliography or reference list. Surname
+    /// particles keep their case ("van Gogh, V."); use `format` with the
+    /// `{Surname}` placeholder where a name must start a sentence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use human_name::{Name, CitationStyle};
+    ///
+    /// let name = Name::parse("Jane Ann Doe, Jr.").unwrap();
+    /// assert_eq!("Doe, J. A., Jr.", name.format_citation(CitationStyle::Apa));
+    /// assert_eq!("Doe, Jane Ann, Jr.", name.format_citation(CitationStyle::Mla));
+    /// assert_eq!("Doe JA Jr", name.format_citation(CitationStyle::Vancouver));
+    /// assert_eq!("DOE Jane Ann Jr.", name.format_citation(CitationStyle::FrenchOfficial));
+    /// ```
+    pub fn format_citation(&self, style: CitationStyle) -> String {
+        self.format(style.pattern())
+    }
+
+    fn placeholder(&self, name: &str) -> Option<Cow<'_, str>> {
+        let value = match name {
+            "given" => {
+                match self.given_name() {
+                    Some(name) => Cow::Borrowed(name),
+                    None => Cow::Owned(format!("{}.", self.first_initial())),
+                }
+            }
+            "given_names" => Cow::Owned(self.given_names_or_initials_after(0)),
+            "middle" => Cow::Owned(self.given_names_or_initials_after(1)),
+            "first_initial" => Cow::Owned(format!("{}.", self.first_initial())),
+            "initials" => Cow::Owned(with_periods(self.initials())),
+            "middle_initials" => Cow::Owned(with_periods(self.middle_initials().unwrap_or(""))),
+            "initials_compact" => Cow::Borrowed(self.initials()),
+            "surname" => self.surname(),
+            "Surname" => Cow::Owned(capitalize_first(&self.surname())),
+            "SURNAME" => Cow::Owned(self.surname().to_uppercase()),
+            "particles" => {
+                let surnames = self.surnames();
+                Cow::Owned(surnames[..leading_particle_count(surnames, SortConvention::Dutch)].join(" "))
+            }
+            "principal_surname" => {
+                let surnames = self.surnames();
+                Cow::Owned(surnames[leading_particle_count(surnames, SortConvention::Dutch)..].join(" "))
+            }
+            "suffix" => Cow::Borrowed(self.suffix().unwrap_or("")),
+            "suffix_compact" => Cow::Owned(self.suffix().unwrap_or("").replace('.', "")),
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    // Given and middle names or initials, as in `display_full`, skipping
+    // the first `skip` parts
+    fn given_names_or_initials_after(&self, skip: usize) -> String {
+        let mut result = String::new();
+        for part in self.given_names_or_initials().skip(skip) {
+            if !result.is_empty() {
+                result.push(' ');
+            }
+
+            match part {
+                NameWordOrInitial::Word(name, _) => {
+                    result.push_str(name);
+                }
+                NameWordOrInitial::Initial(initial) => {
+                    result.push(initial);
+                    result.push('.');
+                }
+            }
+        }
+        result
+    }
+}
+
+fn with_periods(initials: &str) -> String {
+    let mut result = String::with_capacity(initials.len() * 3);
+    for c in initials.chars() {
+        if !result.is_empty() {
+            result.push(' ');
+        }
+        result.push(c);
+        result.push('.');
+    }
+    result
+}
+
+fn capitalize_first(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) => c.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+// Collapses whitespace and removes commas left dangling by empty placeholders
+fn tidy(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for word in s.split_whitespace() {
+        if word == "," {
+            continue;
+        }
+        if !result.is_empty() && !word.starts_with(',') {
+            result.push(' ');
+        }
+        result.push_str(word);
+    }
+
+    while result.ends_with(',') {
+        result.pop();
+    }
+    while result.starts_with(',') {
+        result.remove(0);
+    }
+    result.trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::Name;
+
+    #[test]
+    fn escapes_and_unknown_placeholders() {
+        let name = Name::parse("Jane Doe").unwrap();
+        assert_eq!("{Doe} {nope}", name.format("{{{surname}}} {nope}"));
+    }
+
+    #[test]
+    fn missing_parts() {
+        let name = Name::parse("Jane Doe").unwrap();
+        assert_eq!("Doe, Jane", name.format("{surname}, {given} {middle}, {suffix}"));
+        assert_eq!("Doe", name.format("{middle_initials}, {surname}"));
+    }
+
+    #[test]
+    fn particles() {
+        let name = Name::parse("Ludwig van Beethoven").unwrap();
+        assert_eq!("van Beethoven, L.", name.format_citation(CitationStyle::Apa));
+        assert_eq!("van Beethoven L", name.format_citation(CitationStyle::Vancouver));
+        assert_eq!("VAN BEETHOVEN Ludwig",
+                   name.format_citation(CitationStyle::FrenchOfficial));
+        assert_eq!("Van Beethoven wrote", name.format("{Surname} wrote"));
+        assert_eq!("Beethoven, Ludwig van", name.format_citation(CitationStyle::Dutch));
+    }
+
+    #[test]
+    fn locales() {
+        assert_eq!(Some(CitationStyle::FrenchOfficial), CitationStyle::from_locale("fr"));
+        assert_eq!(Some(CitationStyle::Dutch), CitationStyle::from_locale("NL-be"));
+        assert_eq!(None, CitationStyle::from_locale("de"));
+        assert_eq!(None, CitationStyle::from_locale(""));
+
+        let name = Name::parse("Oscar de la Hoya Jr.").unwrap();
+        assert_eq!("Hoya, Oscar de la, Jr.", name.format_citation(CitationStyle::Dutch));
+
+        let name = Name::parse("Jane Doe").unwrap();
+        assert_eq!("Doe, Jane", name.format_citation(CitationStyle::Dutch));
+    }
+
+    #[test]
+    fn compound_surname() {
+        let name = Name::parse("Juan Alberto T. Velasquez y Garcia").unwrap();
+        assert_eq!("Velasquez y Garcia, J. A. T.", name.format_citation(CitationStyle::Apa));
+        assert_eq!("Velasquez y Garcia, Juan Alberto T.",
+                   name.format_citation(CitationStyle::Mla));
+    }
+
+    #[test]
+    fn goes_by_middle_name() {
+        let name = Name::parse("T. Boone Pickens").unwrap();
+        assert_eq!("Pickens, T. B.", name.format_citation(CitationStyle::Apa));
+        assert_eq!("Pickens, T. Boone", name.format_citation(CitationStyle::Mla));
+    }
+}
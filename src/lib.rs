@@ -4,21 +4,41 @@
 
 #![doc(html_root_url = "https://djudd.github.io/human-name/")]
 
-#![feature(libc)]
-#![feature(plugin)]
-#![plugin(phf_macros)]
+// Idioms this crate has always used, which newer Clippy releases discourage
+#![allow(clippy::redundant_static_lifetimes,
+         clippy::redundant_field_names,
+         clippy::explicit_auto_deref,
+         clippy::borrow_deref_ref,
+         clippy::needless_bool,
+         clippy::if_same_then_else,
+         clippy::collapsible_if,
+         clippy::collapsible_else_if,
+         clippy::unnecessary_unwrap,
+         clippy::items_after_test_module)]
 
+#[macro_use]
 extern crate phf;
 extern crate itertools;
 extern crate unicode_segmentation;
 extern crate unicode_normalization;
 extern crate unidecode;
+#[cfg(not(target_arch = "wasm32"))]
 extern crate rustc_serialize;
+#[cfg(feature = "wasm")]
+extern crate wasm_bindgen;
+#[cfg(feature = "wasm")]
+extern crate js_sys;
+#[cfg(feature = "python")]
+extern crate pyo3;
 
 #[macro_use]
 mod utils;
 mod suffix;
 mod nickname;
+mod diminutive;
+mod cognate;
+mod patronymic;
+mod transliteration;
 mod title;
 mod surname;
 mod namecase;
@@ -27,20 +47,48 @@ mod parse;
 mod comparison;
 mod serialization;
 mod web_match;
-
+mod extract;
+mod organization;
+mod plausibility;
+mod formatting;
+mod sorting;
+mod surname_parts;
+
+#[cfg(not(target_arch = "wasm32"))]
 pub mod external;
 
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(feature = "python")]
+pub mod python;
+
 #[cfg(feature = "name_eq_hash")]
 mod eq_hash;
 
 use std::borrow::Cow;
 use std::cell::Cell;
+#[allow(deprecated)]
 use std::hash::{Hash, Hasher, SipHasher};
 use std::slice::Iter;
 use std::str::Chars;
 use std::iter::{Peekable, Enumerate};
-use itertools::Itertools;
-use utils::{is_mixed_case, transliterate, lowercase_if_alpha};
+pub use web_match::{SlugMatch, SlugMatchMethod, SlugPart};
+pub use extract::{extract, ExtractedName};
+pub use organization::{classify, Kind};
+pub use formatting::CitationStyle;
+pub use sorting::SortConvention;
+pub use surname_parts::{SurnameParts, SurnamePart, SurnamePartKind};
+pub use comparison::{ComparisonOptions, MatchKind};
+pub use diminutive::Language;
+pub use transliteration::{Transliterator, Unidecode, GermanUmlauts, Iso9, BgnPcgn, Hepburn, Pinyin};
+pub use namecase::{namecase, NamecaseOptions};
+pub use nickname::{NicknameDb, NicknameDbError, nicknames_of, formal_names_for};
+
+use utils::{is_mixed_case, capitalize_and_normalize, fold_vowel_digraphs};
+
+// Inputs this long or longer are rejected without parsing
+const MAX_INPUT_LEN: usize = 1000;
 
 /// Represents a parsed human name.
 ///
@@ -65,9 +113,33 @@ pub struct Name {
     generation_from_suffix: Option<usize>,
     initials: String,
     word_indices_in_initials: Vec<(usize, usize)>,
+    patronymics: Vec<String>,
+    extras: Option<Box<Extras>>,
     hash: Cell<Option<u64>>,
 }
 
+// Kept out of line because they are only present if requested
+struct Extras {
+    honorific_prefix: Option<String>,
+    honorific_suffix: Option<String>,
+    nickname: Option<String>,
+}
+
+/// Options controlling the behavior of `Name::parse_with_options`.
+///
+/// The default options give the same behavior as `Name::parse`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Preserve titles and nicknames found in the input, rather than
+    /// discarding them (see `honorific_prefix`, `honorific_suffix` and
+    /// `nickname`).
+    pub preserve_extras: bool,
+    /// Return `None` for input that `classify` considers an organization,
+    /// such as "Smith & Sons LLC" or "University of Michigan", rather than
+    /// parsing it as a person's name.
+    pub refuse_organizations: bool,
+}
+
 impl Name {
 
     /// Parses a string represent a single person's full name into a canonical
@@ -106,16 +178,16 @@ impl Name {
     ///
     /// Errs on the side of producing parse output rather than giving up, so
     /// this function is _not_ suitable as a way of guessing whether a given
-    /// string actually represents a name.
+    /// string actually represents a name (see `plausibility` for that).
     ///
     /// However, success requires at least an apparent surname and first initial.
     /// Single-word names cannot be parsed (you may or may not wish to assume
     /// they are given names).
     ///
     /// Does not preserve titles (other than generational suffixes such as "III")
-    /// or nicknames. Does not handle plural forms specially: "Mr. & Mrs. John
-    /// Doe" will be parsed as "John Doe", and "Jane Doe, et al" will be parsed
-    /// as "Jane Doe".
+    /// or nicknames, unless requested via `parse_with_options`. Does not handle
+    /// plural forms specially: "Mr. & Mrs. John Doe" will be parsed as "John
+    /// Doe", and "Jane Doe, et al" will be parsed as "Jane Doe".
     ///
     /// Works best on Latin names - i.e., data from North or South America or
     /// Europe. Does not understand surname-first formats without commas: "Kim
@@ -130,19 +202,48 @@ impl Name {
     /// for canonicalizing names. The goal here is to do the best we can without
     /// large statistical models.
     pub fn parse(name: &str) -> Option<Name> {
-        if name.len() >= 1000 || !name.chars().any(char::is_alphabetic) {
+        Name::parse_with_options(name, &ParseOptions::default())
+    }
+
+    /// Parses a name as `parse` does, but with non-default options.
+    ///
+    /// # Examples
+    /// ```
+    /// use human_name::{Name, ParseOptions};
+    ///
+    /// let options = ParseOptions { preserve_extras: true, ..ParseOptions::default() };
+    /// let name = Name::parse_with_options("Dr. Robert 'Bob' Roberts, PhD", &options).unwrap();
+    /// assert_eq!("Robert Roberts", name.display_full());
+    /// assert_eq!(Some("Dr."), name.honorific_prefix());
+    /// assert_eq!(Some("PhD"), name.honorific_suffix());
+    /// assert_eq!(Some("Bob"), name.nickname());
+    ///
+    /// let name = Name::parse("Dr. Robert 'Bob' Roberts, PhD").unwrap();
+    /// assert_eq!(None, name.honorific_prefix());
+    ///
+    /// let options = ParseOptions { refuse_organizations: true, ..ParseOptions::default() };
+    /// assert!(Name::parse_with_options("Acme Widgets Inc", &options).is_none());
+    /// assert!(Name::parse("Acme Widgets Inc").is_some());
+    /// ```
+    pub fn parse_with_options(name: &str, options: &ParseOptions) -> Option<Name> {
+        if name.len() >= MAX_INPUT_LEN || !name.chars().any(char::is_alphabetic) {
             return None;
         }
 
-        let mixed_case = is_mixed_case(name);
-        let name = nickname::strip_nickname(name);
-
-        let result = parse::parse(&*name, mixed_case);
-        if result.is_none() {
+        if options.refuse_organizations && organization::classify(name) == Kind::Organization {
             return None;
         }
 
-        let (words, surname_index, generation_from_suffix) = result.unwrap();
+        let mixed_case = is_mixed_case(name);
+        let nickname = if options.preserve_extras {
+            nickname::find_nickname(name)
+        } else {
+            None
+        };
+        let name = nickname::strip_nickname(name);
+
+        let (words, surname_index, generation_from_suffix, titles) =
+            parse::parse(&*name, mixed_case)?;
 
         let mut names: Vec<String> = Vec::with_capacity(words.len());
         let mut initials = String::with_capacity(surname_index);
@@ -165,13 +266,28 @@ impl Name {
                                     .filter_map(|w| w.chars().find(|c| c.is_alphabetic()))
                                     .flat_map(|c| c.to_uppercase()));
 
-                names.push(word.namecased.into_owned());
+                // Particles are only lowercase after the given name, so if
+                // we took one to be part of the given name after all (as in
+                // "VAN NGUYEN"), capitalize it, unless the input was mixed case
+                // and so the lowercase was deliberate
+                if !mixed_case && word.namecased.starts_with(char::is_lowercase) {
+                    names.push(capitalize_and_normalize(&word.namecased));
+                } else {
+                    names.push(word.namecased.into_owned());
+                }
                 word_indices_in_initials.push((prior_len, initials.len()));
             } else {
                 names.push(word.namecased.into_owned());
             }
         }
 
+        // Split off any chain of patronymics ("bin Salman bin Abdulaziz") from
+        // the start of the surname, joining each particle to its name
+        let nasab_len = patronymic::nasab_chain_len(&names[surname_index_in_names..]);
+        let nasab_end = surname_index_in_names + nasab_len;
+        let nasab: Vec<String> = names.drain(surname_index_in_names..nasab_end).collect();
+        let patronymics = nasab.chunks(2).map(|pair| pair.join(" ")).collect();
+
         debug_assert!(!names.is_empty(), "Names are empty!");
         //debug_assert!(initials.len() > 0, "Initials are empty!");
         //
@@ -185,12 +301,32 @@ impl Name {
         names.shrink_to_fit();
         word_indices_in_initials.shrink_to_fit();
 
+        let extras = if options.preserve_extras {
+            let join_titles = |words: Vec<&str>| {
+                if words.is_empty() {
+                    None
+                } else {
+                    Some(words.join(" "))
+                }
+            };
+
+            Some(Box::new(Extras {
+                honorific_prefix: join_titles(titles.prefix),
+                honorific_suffix: join_titles(titles.postfix),
+                nickname: nickname.map(|n| n.to_string()),
+            }))
+        } else {
+            None
+        };
+
         Some(Name {
             words: names,
             surname_index: surname_index_in_names,
             generation_from_suffix: generation_from_suffix,
             initials: initials,
             word_indices_in_initials: word_indices_in_initials,
+            patronymics: patronymics,
+            extras: extras,
             hash: Cell::new(None),
         })
     }
@@ -229,7 +365,7 @@ impl Name {
     }
 
     /// Middle names as a string, if present
-    pub fn middle_name(&self) -> Option<Cow<str>> {
+    pub fn middle_name(&self) -> Option<Cow<'_, str>> {
         match self.middle_names() {
             Some(words) => {
                 if words.len() == 1 {
@@ -256,7 +392,7 @@ impl Name {
     }
 
     /// Surname as a string (always present)
-    pub fn surname(&self) -> Cow<str> {
+    pub fn surname(&self) -> Cow<'_, str> {
         if self.surnames().len() > 1 {
             Cow::Owned(self.surnames().join(" "))
         } else {
@@ -266,10 +402,72 @@ impl Name {
 
     /// Generational suffix, if present
     pub fn suffix(&self) -> Option<&str> {
-        self.generation_from_suffix.map(|g| suffix::display_generational_suffix(g))
+        self.generation_from_suffix.map(suffix::display_generational_suffix)
     }
 
-    fn given_names_or_initials(&self) -> GivenNamesOrInitials {
+    /// Patronymics and matronymics, if present: East Slavic patronymic middle
+    /// names ("Vladimirovich"), Arabic and Hebrew components introduced by
+    /// "bin", "ibn", "ben", "bat" and so on, and Icelandic patronymic surnames
+    /// ("Sigurðsson", "Guðmundsdóttir").
+    ///
+    /// Arabic and Hebrew patronymics preceding a family name are not part of
+    /// the surname, and so are ignored in comparison. East Slavic patronymics
+    /// remain middle names, and Icelandic ones remain the surname, since that's
+    /// how they're used.
+    ///
+    /// ```
+    /// use human_name::Name;
+    ///
+    /// let name = Name::parse("Muhammad bin Salman bin Abdulaziz Al Saud").unwrap();
+    /// assert_eq!(vec!["Bin Salman", "Bin Abdulaziz"], name.patronymics());
+    /// assert_eq!("Al Saud", name.surname());
+    /// assert!(name.consistent_with(&Name::parse("Muhammad Al Saud").unwrap()));
+    ///
+    /// let name = Name::parse("Vladimir Vladimirovich Putin").unwrap();
+    /// assert_eq!(vec!["Vladimirovich"], name.patronymics());
+    /// assert_eq!("Putin", name.surname());
+    ///
+    /// let name = Name::parse("Jane Doe").unwrap();
+    /// assert!(name.patronymics().is_empty());
+    /// ```
+    pub fn patronymics(&self) -> Vec<&str> {
+        let mut result: Vec<&str> = self.middle_names()
+                                        .unwrap_or(&[])
+                                        .iter()
+                                        .filter(|w| patronymic::is_east_slavic_patronymic(w))
+                                        .map(|w| &**w)
+                                        .collect();
+
+        result.extend(self.patronymics.iter().map(|p| &**p));
+
+        let surnames = self.surnames();
+        if surnames.len() == 1 &&
+           patronymic::is_icelandic_patronymic(&surnames[0], &self.words[0..self.surname_index]) {
+            result.push(&surnames[0]);
+        }
+
+        result
+    }
+
+    /// Prefix title(s) as they appeared in the input, if present and preserved
+    /// (see `ParseOptions`)
+    pub fn honorific_prefix(&self) -> Option<&str> {
+        self.extras.as_ref().and_then(|e| e.honorific_prefix.as_ref()).map(|s| &**s)
+    }
+
+    /// Postfix title(s) other than generational suffixes, as they appeared in
+    /// the input, if present and preserved (see `ParseOptions`)
+    pub fn honorific_suffix(&self) -> Option<&str> {
+        self.extras.as_ref().and_then(|e| e.honorific_suffix.as_ref()).map(|s| &**s)
+    }
+
+    /// Nickname as it appeared in the input, without enclosing quotes or
+    /// parentheses, if present and preserved (see `ParseOptions`)
+    pub fn nickname(&self) -> Option<&str> {
+        self.extras.as_ref().and_then(|e| e.nickname.as_ref()).map(|s| &**s)
+    }
+
+    fn given_names_or_initials(&self) -> GivenNamesOrInitials<'_> {
         GivenNamesOrInitials {
             initials: self.initials.chars().enumerate(),
             known_names: self.words[0..self.surname_index].iter(),
@@ -325,7 +523,10 @@ impl Name {
         // Words plus spaces
         let mut len = self.words
                           .iter()
-                          .fold(self.words.len() - 1, |sum, ref word| sum + word.len());
+                          .fold(self.words.len() - 1, |sum, word| sum + word.len());
+
+        // Patronymics plus spaces
+        len += self.patronymics.iter().fold(0, |sum, patronymic| sum + patronymic.len() + 1);
 
         if let Some(suffix) = self.suffix() {
             len += 2; // Comma and space
@@ -337,7 +538,7 @@ impl Name {
             len += self.initials.len() -
                    self.words[0..self.surname_index]
                        .iter()
-                       .fold(0, |sum, ref word| sum + word.chars().nth(0).unwrap().len_utf8());
+                       .fold(0, |sum, word| sum + word.chars().nth(0).unwrap().len_utf8());
 
             len += 2 * extra_initials; // Period and space for each initial
         }
@@ -346,7 +547,7 @@ impl Name {
     }
 
     /// The full name, or as much of it as was preserved from the input,
-    /// including given name, middle names, surname and suffix.
+    /// including given name, middle names, patronymics, surname and suffix.
     ///
     /// ```
     /// use human_name::Name;
@@ -370,6 +571,11 @@ impl Name {
             }
         }
 
+        for patronymic in self.patronymics.iter() {
+            result.push_str(patronymic);
+            result.push(' ');
+        }
+
         let surnames = self.surnames();
         if surnames.len() > 1 {
             for word in surnames[0..surnames.len() - 1].iter() {
@@ -396,7 +602,10 @@ impl Name {
     ///
     /// We can only use the last four alphabetical characters of the surname,
     /// because that's all we're guaranteed to use in the consistency test. That
-    /// means if names are ASCII, we only have 19 bits of variability.
+    /// means if names are ASCII, we only have 19 bits of variability. (As in
+    /// the consistency test, umlauts and vowel digraphs are folded first, so
+    /// "Mueller" and "Müller" both hash as "ller"; this fold is coarser than
+    /// the test's, so e.g. "Baez" and "Baz" hash equally but aren't consistent.)
     ///
     /// That means if you are working with a lot of names and you expect surnames
     /// to be similar or identical, you might be better off avoiding hash-based
@@ -409,18 +618,61 @@ impl Name {
     /// We can't use the first initial because we might ignore it if someone goes
     /// by a middle name or nickname, or due to transliteration.
     pub fn surname_hash<H: Hasher>(&self, state: &mut H) {
-        let surname_chars = self.surnames()
-                                .iter()
-                                .flat_map(|w| w.chars())
-                                .flat_map(transliterate)
-                                .rev();
-        for c in surname_chars.filter_map(lowercase_if_alpha)
-                              .take(comparison::MIN_SURNAME_CHAR_MATCH) {
+        self.surname_hash_with_options(&ComparisonOptions::default(), state)
+    }
+
+    /// As `surname_hash`, but consistent with comparison using a particular
+    /// transliteration scheme (see `ComparisonOptions`).
+    ///
+    /// Names which hash equally under one scheme may not under another, so
+    /// don't mix hashes made with different schemes.
+    pub fn surname_hash_with<H: Hasher>(&self, transliterator: &dyn Transliterator, state: &mut H) {
+        let options = ComparisonOptions {
+            transliterator: Some(transliterator),
+            ..ComparisonOptions::default()
+        };
+        self.surname_hash_with_options(&options, state)
+    }
+
+    /// As `surname_hash`, but consistent with `consistent_with_options` for
+    /// the given options, e.g. for blocking names by surname when comparing
+    /// gendered surname forms.
+    ///
+    /// Don't mix hashes made with different options.
+    ///
+    /// # Examples
+    /// ```
+    /// use human_name::{Name, ComparisonOptions};
+    /// use std::hash::{Hasher, SipHasher};
+    ///
+    /// fn hash(name: &Name, options: &ComparisonOptions) -> u64 {
+    ///     let mut s = SipHasher::new();
+    ///     name.surname_hash_with_options(options, &mut s);
+    ///     s.finish()
+    /// }
+    ///
+    /// let options = ComparisonOptions { gendered_surnames: true, ..ComparisonOptions::default() };
+    /// let jan = Name::parse("Jan Kowalski").unwrap();
+    /// let j = Name::parse("J. Kowalska").unwrap();
+    /// let anna = Name::parse("Anna Kowalska").unwrap();
+    ///
+    /// assert!(jan.consistent_with_options(&j, &options));
+    /// assert!(!jan.consistent_with(&j));
+    /// assert_eq!(hash(&jan, &options), hash(&anna, &options));
+    /// ```
+    pub fn surname_hash_with_options<H: Hasher>(&self, options: &ComparisonOptions, state: &mut H) {
+        let words = comparison::comparable_surname_words(self, options);
+        let surname = fold_vowel_digraphs(&words.concat());
+        for c in surname.chars().rev().take(comparison::MIN_SURNAME_CHAR_MATCH) {
             c.hash(state);
         }
     }
 
     /// Memoizes the result of `surname_hash` when used with `SipHasher`
+    //
+    // SipHasher is deprecated, but unlike DefaultHasher its output is fixed
+    // across Rust releases, and callers may have stored these hashes
+    #[allow(deprecated)]
     pub fn memoized_surname_hash(&self) -> u64 {
         {
             let cached = self.hash.get();
@@ -1,6 +1,11 @@
-#![feature(test)]
+#![cfg_attr(feature = "bench", feature(test))]
+// The command-line client isn't built for WebAssembly (see src/wasm.rs)
+#![cfg_attr(target_arch = "wasm32", no_main)]
+#![cfg(not(target_arch = "wasm32"))]
+#![allow(clippy::redundant_static_lifetimes, clippy::collapsible_if, clippy::unnecessary_unwrap)]
 
 extern crate human_name;
+#[cfg(feature = "bench")]
 extern crate test;
 extern crate rustc_serialize;
 
@@ -9,13 +14,15 @@ use std::process;
 use std::io;
 use std::io::BufReader;
 use std::io::prelude::*;
-use rustc_serialize::json::ToJson;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use rustc_serialize::json::{Json, ToJson};
 
-#[cfg_attr(rustfmt, rustfmt_skip)]
+#[rustfmt::skip]
 const USAGE: &'static str = "
 Usage:
-    human_name parse <name>
-    human_name parse -
+    human_name parse [options] <name>
+    human_name parse [options] -
     human_name eq '<name1>' '<name2>'
     human_name eq '<name>' -
 
@@ -28,23 +35,73 @@ not.
 With the `parse` command, it will run in parsing mode. If `-` is the argument,
 it will expect newline-separated names to parse from stdin. Otherwise, it will
 try to parse the arguments as a name, exiting with status 0 if it succeeds, and
-status 1 otherwise. In either case it will print parsed output as JSON.
+status 1 otherwise. In either case it will print parsed output, by default as
+JSON.
+
+Options for `parse`:
+    --format=<format>   Output format: json (the default), csv, tsv,
+                        display-full, first-last, or initial-surname. The csv
+                        and tsv formats begin with a header row.
+    --include-input     Echo each input string alongside its output: as an
+                        \"input\" key in JSON, or as the first column or
+                        tab-separated field otherwise.
+    --failures=<mode>   How to report input that can't be parsed: empty (the
+                        default) prints an empty record, skip prints nothing,
+                        and error-json prints a JSON object with \"error\" and
+                        \"input\" keys regardless of --format.
 ";
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    Json,
+    Csv,
+    Tsv,
+    DisplayFull,
+    FirstLast,
+    InitialSurname,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Failures {
+    Skip,
+    Empty,
+    ErrorJson,
+}
+
+struct CliOptions {
+    format: Format,
+    include_input: bool,
+    failures: Failures,
+}
+
+static CSV_COLUMNS: [&'static str; 6] = ["surname",
+                                         "given_name",
+                                         "middle_names",
+                                         "first_initial",
+                                         "middle_initials",
+                                         "suffix"];
+
 fn main() {
     let args: Vec<String> = env::args().collect();
 
     if args.len() == 4 && args[1] == "eq" {
         equality_mode(&args);
     } else if args.len() > 2 && args[1] == "parse" {
-        parse_mode(&args);
+        match parse_options(&args[2..]) {
+            Some((options, inputs)) => parse_mode(&options, &inputs),
+            None => usage(),
+        }
     } else {
-        writeln!(&mut std::io::stderr(), "{}", USAGE).ok().unwrap();
-        process::exit(64);
+        usage();
     }
 }
 
-fn equality_mode(args: &Vec<String>) {
+fn usage() -> ! {
+    writeln!(&mut std::io::stderr(), "{}", USAGE).ok().unwrap();
+    process::exit(64);
+}
+
+fn equality_mode(args: &[String]) {
     if args[2] == "-" {
         let parsed_a = human_name::Name::parse(&args[3]);
         if parsed_a.is_none() {
@@ -58,7 +115,7 @@ fn equality_mode(args: &Vec<String>) {
                 Some(input) => {
                     let parsed_b = human_name::Name::parse(&input);
                     if parsed_a == parsed_b {
-                        if !writeln!(&mut io::stdout(), "{}", input.trim()).is_ok() {
+                        if writeln!(&mut io::stdout(), "{}", input.trim()).is_err() {
                             break;
                         }
                     };
@@ -84,24 +141,86 @@ fn equality_mode(args: &Vec<String>) {
     }
 }
 
-fn parse_mode(args: &Vec<String>) {
-    if args[2] == "-" {
+// Returns None if the options are invalid, or if there's no input
+fn parse_options(args: &[String]) -> Option<(CliOptions, Vec<String>)> {
+    let mut options = CliOptions {
+        format: Format::Json,
+        include_input: false,
+        failures: Failures::Empty,
+    };
+    let mut inputs = Vec::with_capacity(args.len());
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if !arg.starts_with("--") {
+            inputs.push(arg.clone());
+            continue;
+        }
+
+        let (flag, value) = match arg.find('=') {
+            Some(i) => (&arg[..i], Some(arg[i + 1..].to_string())),
+            None => (&arg[..], None),
+        };
+
+        if flag == "--include-input" && value.is_none() {
+            options.include_input = true;
+            continue;
+        }
+
+        // Also accept the value as a separate argument, e.g. `--format csv`
+        let value = value.or_else(|| args.next().cloned())?;
+
+        if flag == "--format" {
+            options.format = match &*value {
+                "json" => Format::Json,
+                "csv" => Format::Csv,
+                "tsv" => Format::Tsv,
+                "display-full" => Format::DisplayFull,
+                "first-last" => Format::FirstLast,
+                "initial-surname" => Format::InitialSurname,
+                _ => return None,
+            };
+        } else if flag == "--failures" {
+            options.failures = match &*value {
+                "skip" => Failures::Skip,
+                "empty" => Failures::Empty,
+                "error-json" => Failures::ErrorJson,
+                _ => return None,
+            };
+        } else {
+            return None;
+        }
+    }
+
+    if inputs.is_empty() {
+        None
+    } else {
+        Some((options, inputs))
+    }
+}
+
+fn parse_mode(options: &CliOptions, inputs: &[String]) {
+    if let Some(header) = header(options) {
+        if !writeln!(&mut io::stdout(), "{}", header).is_ok() {
+            return;
+        }
+    }
+
+    if inputs.len() == 1 && inputs[0] == "-" {
         let reader = BufReader::new(io::stdin());
         for line in reader.lines() {
             match line.ok() {
                 Some(input) => {
                     let parsed = human_name::Name::parse(&input);
                     let output = match parsed {
-                        Some(name) => {
-                            name.to_json().to_string()
-                        }
-                        None => {
-                            "".to_string()
-                        }
+                        Some(name) => Some(format_name(&name, &input, options)),
+                        None => format_failure(&input, options),
                     };
 
-                    if !writeln!(&mut io::stdout(), "{}", output).is_ok() {
-                        break;
+                    if let Some(output) = output {
+                        if !writeln!(&mut io::stdout(), "{}", output).is_ok() {
+                            break;
+                        }
                     }
                 }
                 None => {
@@ -110,16 +229,289 @@ fn parse_mode(args: &Vec<String>) {
             }
         }
     } else {
-        let parsed = human_name::Name::parse(&args[2..].join(" "));
+        let input = inputs.join(" ");
+        let parsed = human_name::Name::parse(&input);
         if parsed.is_none() {
+            if let Some(output) = format_failure(&input, options) {
+                println!("{}", output);
+            }
             process::exit(1);
         } else {
-            println!("{}", parsed.unwrap().to_json().to_string());
+            println!("{}", format_name(&parsed.unwrap(), &input, options));
+        }
+    }
+}
+
+fn header(options: &CliOptions) -> Option<String> {
+    let separator = match options.format {
+        Format::Csv => ",",
+        Format::Tsv => "\t",
+        _ => return None,
+    };
+
+    let mut columns: Vec<&str> = Vec::with_capacity(CSV_COLUMNS.len() + 1);
+    if options.include_input {
+        columns.push("input");
+    }
+    columns.extend(CSV_COLUMNS.iter().cloned());
+
+    Some(columns.join(separator))
+}
+
+fn format_name(name: &human_name::Name, input: &str, options: &CliOptions) -> String {
+    match options.format {
+        Format::Json => {
+            let mut json = name.to_json();
+            if options.include_input {
+                if let Json::Object(ref mut d) = json {
+                    d.insert("input".to_string(), input.to_json());
+                }
+            }
+            json.to_string()
+        }
+        Format::Csv | Format::Tsv => {
+            let middle_name = name.middle_name();
+            let first_initial = name.first_initial().to_string();
+            let surname = name.surname();
+            let fields = [&*surname,
+                          name.given_name().unwrap_or(""),
+                          middle_name.as_deref().unwrap_or(""),
+                          &*first_initial,
+                          name.middle_initials().unwrap_or(""),
+                          name.suffix().unwrap_or("")];
+            format_record(&fields, input, options)
+        }
+        Format::DisplayFull => with_input(name.display_full(), input, options),
+        Format::FirstLast => with_input(name.display_first_last(), input, options),
+        Format::InitialSurname => with_input(name.display_initial_surname(), input, options),
+    }
+}
+
+fn format_failure(input: &str, options: &CliOptions) -> Option<String> {
+    match options.failures {
+        Failures::Skip => None,
+        Failures::ErrorJson => {
+            let mut d = BTreeMap::new();
+            d.insert("error".to_string(), "parse failed".to_json());
+            d.insert("input".to_string(), input.to_json());
+            Some(Json::Object(d).to_string())
+        }
+        Failures::Empty => {
+            match options.format {
+                Format::Json => {
+                    if options.include_input {
+                        let mut d = BTreeMap::new();
+                        d.insert("input".to_string(), input.to_json());
+                        Some(Json::Object(d).to_string())
+                    } else {
+                        Some("".to_string())
+                    }
+                }
+                Format::Csv | Format::Tsv => {
+                    let fields = [""; 6];
+                    Some(format_record(&fields, input, options))
+                }
+                _ => Some(with_input("".to_string(), input, options)),
+            }
         }
     }
 }
 
+fn format_record(fields: &[&str], input: &str, options: &CliOptions) -> String {
+    let escape: fn(&str) -> Cow<str> = if options.format == Format::Csv {
+        escape_csv
+    } else {
+        escape_tsv
+    };
+    let separator = if options.format == Format::Csv {
+        ","
+    } else {
+        "\t"
+    };
+
+    let mut escaped: Vec<Cow<str>> = Vec::with_capacity(fields.len() + 1);
+    if options.include_input {
+        escaped.push(escape(input));
+    }
+    escaped.extend(fields.iter().map(|f| escape(f)));
+
+    escaped.join(separator)
+}
+
+fn with_input(output: String, input: &str, options: &CliOptions) -> String {
+    if options.include_input {
+        format!("{}\t{}", escape_tsv(input), output)
+    } else {
+        output
+    }
+}
+
+fn escape_csv(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+fn escape_tsv(field: &str) -> Cow<'_, str> {
+    if field.contains(['\t', '\n', '\r']) {
+        Cow::Owned(field.replace(['\t', '\n', '\r'], " "))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
 #[cfg(test)]
+mod tests {
+    use super::*;
+    use human_name::Name;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    fn options(args_: &[&str]) -> CliOptions {
+        parse_options(&args(args_)).unwrap().0
+    }
+
+    fn options_with(format: Format, include_input: bool) -> CliOptions {
+        CliOptions {
+            format,
+            include_input,
+            failures: Failures::Empty,
+        }
+    }
+
+    #[test]
+    fn defaults() {
+        let (options, inputs) = parse_options(&args(&["Jane", "Doe"])).unwrap();
+        assert_eq!(Format::Json, options.format);
+        assert_eq!(Failures::Empty, options.failures);
+        assert!(!options.include_input);
+        assert_eq!(args(&["Jane", "Doe"]), inputs);
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(Format::Json, options(&["--format=json", "-"]).format);
+        assert_eq!(Format::Csv, options(&["--format=csv", "-"]).format);
+        assert_eq!(Format::Tsv, options(&["--format=tsv", "-"]).format);
+        assert_eq!(Format::DisplayFull, options(&["--format=display-full", "-"]).format);
+        assert_eq!(Format::FirstLast, options(&["--format=first-last", "-"]).format);
+        assert_eq!(Format::InitialSurname, options(&["--format=initial-surname", "-"]).format);
+        assert_eq!(Format::Csv, options(&["--format", "csv", "-"]).format);
+    }
+
+    #[test]
+    fn failures() {
+        assert_eq!(Failures::Skip, options(&["--failures=skip", "-"]).failures);
+        assert_eq!(Failures::Empty, options(&["--failures=empty", "-"]).failures);
+        assert_eq!(Failures::ErrorJson, options(&["--failures=error-json", "-"]).failures);
+        assert_eq!(Failures::Skip, options(&["--failures", "skip", "-"]).failures);
+    }
+
+    #[test]
+    fn include_input() {
+        assert!(options(&["--include-input", "-"]).include_input);
+        assert!(parse_options(&args(&["--include-input=yes", "-"])).is_none());
+    }
+
+    #[test]
+    fn invalid_options() {
+        assert!(parse_options(&args(&["--format=xml", "-"])).is_none());
+        assert!(parse_options(&args(&["--format=CSV", "-"])).is_none());
+        assert!(parse_options(&args(&["--failures=ignore", "-"])).is_none());
+        assert!(parse_options(&args(&["--verbose", "-"])).is_none());
+        assert!(parse_options(&args(&["--format"])).is_none());
+        assert!(parse_options(&args(&["--format=csv"])).is_none());
+        assert!(parse_options(&args(&[])).is_none());
+    }
+
+    #[test]
+    fn csv_escaping() {
+        assert_eq!("Doe", escape_csv("Doe"));
+        assert_eq!("\"Doe, Jane\"", escape_csv("Doe, Jane"));
+        assert_eq!("\"Jane \"\"JD\"\" Doe\"", escape_csv("Jane \"JD\" Doe"));
+        assert_eq!("\"Jane\nDoe\"", escape_csv("Jane\nDoe"));
+        assert_eq!("\"Jane\r\nDoe\"", escape_csv("Jane\r\nDoe"));
+        assert_eq!("Jane\tDoe", escape_csv("Jane\tDoe"));
+    }
+
+    #[test]
+    fn tsv_escaping() {
+        assert_eq!("Doe, Jane", escape_tsv("Doe, Jane"));
+        assert_eq!("Jane Doe", escape_tsv("Jane\tDoe"));
+        assert_eq!("Jane Doe", escape_tsv("Jane\nDoe"));
+        assert_eq!("Jane  Doe", escape_tsv("Jane\r\nDoe"));
+        assert_eq!("\"Jane\"", escape_tsv("\"Jane\""));
+    }
+
+    #[test]
+    fn headers() {
+        assert_eq!(None, header(&options_with(Format::Json, true)));
+        assert_eq!(None, header(&options_with(Format::DisplayFull, true)));
+        assert_eq!(Some("surname,given_name,middle_names,first_initial,middle_initials,suffix"
+                            .to_string()),
+                   header(&options_with(Format::Csv, false)));
+        assert_eq!(Some("input\tsurname\tgiven_name\tmiddle_names\tfirst_initial\t\
+                         middle_initials\tsuffix"
+                            .to_string()),
+                   header(&options_with(Format::Tsv, true)));
+    }
+
+    #[test]
+    fn output_with_input() {
+        let input = "Doe, Jane";
+        let name = Name::parse(input).unwrap();
+        let format = |format| format_name(&name, input, &options_with(format, true));
+
+        assert_eq!(r#"{"first_initial":"J","given_name":"Jane","input":"Doe, Jane","surname":"Doe"}"#,
+                   format(Format::Json));
+        assert_eq!("\"Doe, Jane\",Doe,Jane,,J,,", format(Format::Csv));
+        assert_eq!("Doe, Jane\tDoe\tJane\t\tJ\t\t", format(Format::Tsv));
+        assert_eq!("Doe, Jane\tJane Doe", format(Format::DisplayFull));
+        assert_eq!("Doe, Jane\tJane Doe", format(Format::FirstLast));
+        assert_eq!("Doe, Jane\tJ. Doe", format(Format::InitialSurname));
+    }
+
+    #[test]
+    fn output_without_input() {
+        let input = "Doe, Jane";
+        let name = Name::parse(input).unwrap();
+        let format = |format| format_name(&name, input, &options_with(format, false));
+
+        assert_eq!(r#"{"first_initial":"J","given_name":"Jane","surname":"Doe"}"#,
+                   format(Format::Json));
+        assert_eq!("Doe,Jane,,J,,", format(Format::Csv));
+        assert_eq!("Jane Doe", format(Format::DisplayFull));
+    }
+
+    #[test]
+    fn failures_with_input() {
+        let input = "foo@bar.com";
+        let format = |format, failures| {
+            let options = CliOptions {
+                format,
+                include_input: true,
+                failures,
+            };
+            format_failure(input, &options)
+        };
+
+        assert_eq!(Some(r#"{"input":"foo@bar.com"}"#.to_string()),
+                   format(Format::Json, Failures::Empty));
+        assert_eq!(Some("foo@bar.com,,,,,,".to_string()), format(Format::Csv, Failures::Empty));
+        assert_eq!(Some("foo@bar.com\t\t\t\t\t\t".to_string()),
+                   format(Format::Tsv, Failures::Empty));
+        assert_eq!(Some("foo@bar.com\t".to_string()), format(Format::FirstLast, Failures::Empty));
+        assert_eq!(None, format(Format::Csv, Failures::Skip));
+        assert_eq!(Some(r#"{"error":"parse failed","input":"foo@bar.com"}"#.to_string()),
+                   format(Format::Csv, Failures::ErrorJson));
+    }
+}
+
+#[cfg(all(test, feature = "bench"))]
 mod bench {
     use std::collections::HashSet;
     use std::io::prelude::*;
@@ -1,5 +1,6 @@
 use phf;
-use super::utils::capitalize_and_normalize;
+use super::utils::{capitalize_and_normalize, starts_with_consonant};
+use unicode_normalization::UnicodeNormalization;
 
 // Store capitalized versions because we check after doing the initial,
 // naive capitalization
@@ -14,6 +15,7 @@ static UNCAPITALIZED_PARTICLES: phf::Set<&'static str> = phf_set! {
     "Del",
     "Dela",
     "Dei",
+    "Den",
     "Der",
     "Di",
     "Dí",
@@ -23,6 +25,7 @@ static UNCAPITALIZED_PARTICLES: phf::Set<&'static str> = phf_set! {
     "La",
     "Le",
     "Na",
+    "Ten",
     "Ter",
     "Van",
     "Vel",
@@ -33,6 +36,22 @@ static UNCAPITALIZED_PARTICLES: phf::Set<&'static str> = phf_set! {
     "Y",
 };
 
+// Particles from romanized Arabic and Malay names, which we only lowercase in
+// `namecase`, since parsing treats them as part of the surname ("Bin Laden")
+static ROMANIZED_PARTICLES: phf::Set<&'static str> = phf_set! {
+    "Bin",
+    "Binti",
+    "Bint",
+    "Ibn",
+};
+
+// Words after which Irish names beginning with a vowel take a lowercase "h"
+// ("Ó hAodha", "Ní hUiginn")
+static IRISH_H_PREFIX_TRIGGERS: [&'static str; 3] = ["Ó", "Ní", "Uí"];
+
+static ROMAN_NUMERALS: [&'static str; 10] =
+    ["I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X"];
+
 static MAC_EXCEPTIONS: phf::Set<&'static str> = phf_set! {
     "Machin",
     "Machlin",
@@ -59,20 +78,188 @@ fn capitalize_after_mac(word: &str) -> bool {
     }
 }
 
-pub fn namecase(word: &str, might_be_particle: bool) -> String {
+// Capitalization fixes for a single word (or part of a hyphenated word),
+// after naive capitalization
+fn fix_capitalized(word: String) -> String {
+    if word.starts_with("Mac") && capitalize_after_mac(&word) {
+        "Mac".to_string() + &capitalize_and_normalize(&word[3..])
+    } else if word.starts_with("Mc") && word.len() > 3 {
+        "Mc".to_string() + &capitalize_and_normalize(&word[2..])
+    } else if word.starts_with("Ij") && starts_with_consonant(&word[2..]) {
+        // Dutch "IJ" digraph, e.g. "IJsselstein"
+        "IJ".to_string() + &word[2..]
+    } else {
+        // Normal case
+        word
+    }
+}
+
+pub fn namecase_word(word: &str, might_be_particle: bool) -> String {
     let result = capitalize_and_normalize(word);
 
     if might_be_particle && UNCAPITALIZED_PARTICLES.contains(&*result) {
         result.to_lowercase()
-    } else if result.starts_with("Mac") && capitalize_after_mac(&result) {
-        "Mac".to_string() + &capitalize_and_normalize(&result[3..])
-    } else if result.starts_with("Mc") && result.len() > 3 {
-        "Mc".to_string() + &capitalize_and_normalize(&result[2..])
     } else if result.starts_with("Al-") && result.len() > 3 {
         "al-".to_string() + &result[3..]
+    } else if result.contains('-') {
+        result.split('-')
+              .map(|part| fix_capitalized(part.to_string()))
+              .collect::<Vec<_>>()
+              .join("-")
     } else {
-        // Normal case
-        result
+        fix_capitalized(result)
+    }
+}
+
+/// Options controlling the behavior of `namecase`.
+///
+/// The default options lowercase surname particles after the first word, and
+/// have no exceptions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NamecaseOptions<'a> {
+    /// Capitalize particles such as "van", "de" and "bin" wherever they
+    /// appear, rather than lowercasing them after the first word.
+    pub capitalize_particles: bool,
+
+    /// Capitalize the name following "Fitz", as in "FitzGerald" and
+    /// "FitzRoy", rather than "Fitzgerald" and "Fitzroy".
+    pub capitalize_after_fitz: bool,
+
+    /// Words to case exactly as given, wherever they appear, in place of the
+    /// usual rules (e.g. `&["MacIntyre", "deVries"]`). Matched without regard
+    /// to case.
+    pub exceptions: &'a [&'a str],
+}
+
+/// Fixes the capitalization of a string containing one or more names, such
+/// as an all-caps or all-lowercase database field, without parsing it.
+///
+/// Handles Scottish and Irish "Mac" and "Mc" (with exceptions such as
+/// "Macias"), optionally "Fitz", Irish "O'" and "Ó", other names with an
+/// apostrophe ("D'Angelo", "N'Diaye"), Dutch "IJ", surname particles ("van",
+/// "de la", "bin"), hyphenated names, and Roman numerals at the end of the
+/// name. Particles are only lowercased after the first word, so "VAN DER
+/// BERG" becomes "Van der Berg" but "JAN VAN DER BERG" becomes "Jan van der
+/// Berg". Other single letters are treated as initials, except for the
+/// Spanish and Portuguese conjunctions "y" and "e" between two longer words
+/// ("Velasquez y Garcia"). Whitespace is preserved, and the result is in NFC
+/// normal form.
+///
+/// # Examples
+///
+/// ```
+/// use human_name::{namecase, NamecaseOptions};
+///
+/// let options = NamecaseOptions::default();
+/// assert_eq!("Ludwig van Beethoven", namecase("LUDWIG VAN BEETHOVEN", options));
+/// assert_eq!("Mary-Kate MacDonald III", namecase("mary-kate macdonald iii", options));
+/// assert_eq!("Seán Ó hAodha", namecase("SEÁN Ó HAODHA", options));
+///
+/// let options = NamecaseOptions { exceptions: &["MacIntyre", "Macdonald"], ..options };
+/// assert_eq!("Alasdair Macdonald", namecase("ALASDAIR MACDONALD", options));
+/// ```
+pub fn namecase(text: &str, options: NamecaseOptions) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut previous: Option<String> = None;
+    let mut rest = text;
+
+    loop {
+        let start = rest.find(|c: char| !c.is_whitespace()).unwrap_or(rest.len());
+        result.push_str(&rest[0..start]);
+        rest = &rest[start..];
+
+        if rest.is_empty() {
+            break;
+        }
+
+        let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+        let word = &rest[0..end];
+        rest = &rest[end..];
+
+        let next = rest.split_whitespace().next();
+        let cased = namecase_in_context(word, previous.as_deref(), next, &options);
+
+        result.push_str(&cased);
+        previous = Some(cased);
+    }
+
+    result.nfc().collect()
+}
+
+fn namecase_in_context(word: &str,
+                       previous: Option<&str>,
+                       next: Option<&str>,
+                       options: &NamecaseOptions)
+                       -> String {
+    // Keep a trailing comma out of the way of the checks below
+    let core = word.trim_end_matches(',');
+    let trailing = &word[core.len()..];
+
+    let lower: String = core.nfc().collect::<String>().to_lowercase();
+    let exception = options.exceptions.iter().find(|exception| {
+        exception.nfc().collect::<String>().to_lowercase() == lower
+    });
+    if let Some(exception) = exception {
+        return format!("{}{}", exception, trailing);
+    }
+
+    let is_first = previous.is_none();
+    let is_last = next.is_none();
+    let capitalized = capitalize_and_normalize(core);
+
+    if !is_first && (is_last || !trailing.is_empty()) {
+        let upper = core.to_uppercase();
+        if ROMAN_NUMERALS.contains(&&*upper) {
+            return upper + trailing;
+        }
+    }
+
+    if let Some(previous) = previous {
+        let previous: String = previous.nfc().collect();
+        if IRISH_H_PREFIX_TRIGGERS.contains(&&*previous) && capitalized.len() > 2 &&
+           capitalized.starts_with('H') && starts_with_vowel(&capitalized[1..]) {
+            return format!("h{}{}", capitalize_and_normalize(&capitalized[1..]), trailing);
+        }
+    }
+
+    // As in `find_surname_index`, a "y" or "e" between two longer words is a
+    // conjunction in a Spanish or Portuguese surname rather than an initial
+    let is_longer_word = |w: &str| w.chars().filter(|c| c.is_alphabetic()).count() > 1;
+    if !options.capitalize_particles && trailing.is_empty() && (lower == "y" || lower == "e") &&
+       previous.is_some_and(|w| !w.ends_with(',') && is_longer_word(w)) &&
+       next.is_some_and(&is_longer_word) {
+        return lower;
+    }
+
+    let might_be_particle = !is_first && !options.capitalize_particles &&
+                            core.chars().count() > 1;
+
+    if might_be_particle && ROMANIZED_PARTICLES.contains(&*capitalized) {
+        return capitalized.to_lowercase() + trailing;
+    }
+
+    let result = namecase_word(core, might_be_particle);
+
+    if options.capitalize_after_fitz {
+        result.split('-')
+              .map(|part| {
+                  if part.starts_with("Fitz") && part.len() > 4 {
+                      "Fitz".to_string() + &capitalize_and_normalize(&part[4..])
+                  } else {
+                      part.to_string()
+                  }
+              })
+              .collect::<Vec<_>>()
+              .join("-") + trailing
+    } else {
+        result + trailing
+    }
+}
+
+fn starts_with_vowel(word: &str) -> bool {
+    match word.chars().next() {
+        Some(c) => "AEIOUaeiou".contains(c),
+        None => false,
     }
 }
 
@@ -82,53 +269,249 @@ mod tests {
 
     #[test]
     fn simple() {
-        assert_eq!("Doe", namecase("doe", true));
+        assert_eq!("Doe", namecase_word("doe", true));
     }
 
     #[test]
     fn conjunction() {
-        assert_eq!("y", namecase("y", true));
-        assert_eq!("Y", namecase("y", false));
+        assert_eq!("y", namecase_word("y", true));
+        assert_eq!("Y", namecase_word("y", false));
     }
 
     #[test]
     fn particle() {
-        assert_eq!("de", namecase("de", true));
-        assert_eq!("De", namecase("de", false));
+        assert_eq!("de", namecase_word("de", true));
+        assert_eq!("De", namecase_word("de", false));
     }
 
     #[test]
     fn mcallen() {
-        assert_eq!("McAllen", namecase("mcallen", true));
+        assert_eq!("McAllen", namecase_word("mcallen", true));
     }
 
     #[test]
     fn macmurdo() {
-        assert_eq!("MacMurdo", namecase("macmurdo", true));
+        assert_eq!("MacMurdo", namecase_word("macmurdo", true));
     }
 
     #[test]
     fn machlin() {
-        assert_eq!("Machlin", namecase("machlin", true));
+        assert_eq!("Machlin", namecase_word("machlin", true));
     }
 
     #[test]
     fn maciej() {
-        assert_eq!("Maciej", namecase("maciej", true));
+        assert_eq!("Maciej", namecase_word("maciej", true));
     }
 
     #[test]
     fn mach() {
-        assert_eq!("Mach", namecase("mach", true));
+        assert_eq!("Mach", namecase_word("mach", true));
     }
 
     #[test]
     fn macadaidh() {
-        assert_eq!("MacAdaidh", namecase("macadaidh", true));
+        assert_eq!("MacAdaidh", namecase_word("macadaidh", true));
     }
 
     #[test]
     fn al_amir() {
-        assert_eq!("al-Amir", namecase("al-amir", true));
+        assert_eq!("al-Amir", namecase_word("al-amir", true));
+    }
+
+    #[test]
+    fn hyphenated() {
+        assert_eq!("Smith-MacDonald", namecase_word("smith-macdonald", true));
+        assert_eq!("McAllen-Doe", namecase_word("MCALLEN-DOE", true));
+    }
+
+    #[test]
+    fn ij() {
+        assert_eq!("IJsselstein", namecase_word("ijsselstein", true));
+        assert_eq!("IJsselstein", namecase_word("IJSSELSTEIN", true));
+        assert_eq!("Ija", namecase_word("ija", true));
+    }
+
+    fn namecase_default(text: &str) -> String {
+        namecase(text, NamecaseOptions::default())
+    }
+
+    #[test]
+    fn whole_string() {
+        assert_eq!("Ludwig van Beethoven", namecase_default("LUDWIG VAN BEETHOVEN"));
+        assert_eq!("Van Morrison", namecase_default("VAN MORRISON"));
+        assert_eq!("Oscar de la Hoya", namecase_default("oscar de la hoya"));
+        assert_eq!("Mary  Ann\tDoe", namecase_default("MARY  ANN\tDOE"));
+        assert_eq!("", namecase_default(""));
+        assert_eq!("  ", namecase_default("  "));
+    }
+
+    #[test]
+    fn whole_string_mac() {
+        assert_eq!("Angus MacDonald", namecase_default("ANGUS MACDONALD"));
+        assert_eq!("Ronald McDonald", namecase_default("RONALD MCDONALD"));
+        assert_eq!("Ricky Macias", namecase_default("RICKY MACIAS"));
+    }
+
+    #[test]
+    fn whole_string_irish() {
+        assert_eq!("Conan O'Brien", namecase_default("CONAN O'BRIEN"));
+        assert_eq!("Seán Ó Briain", namecase_default("SEÁN Ó BRIAIN"));
+        assert_eq!("Máire Ní hUiginn", namecase_default("MÁIRE NÍ HUIGINN"));
+        assert_eq!("Ciarán Ó hAodha", namecase_default("ciarán ó haodha"));
+        assert_eq!("Liam Hughes", namecase_default("LIAM HUGHES"));
+    }
+
+    #[test]
+    fn whole_string_romanized_particles() {
+        assert_eq!("Muhammad bin Salman Al Saud",
+                   namecase_default("MUHAMMAD BIN SALMAN AL SAUD"));
+        assert_eq!("Ali ibn Abi Talib", namecase_default("ALI IBN ABI TALIB"));
+        assert_eq!("Bin Laden", namecase_default("BIN LADEN"));
+        assert_eq!("Hassan al-Amir", namecase_default("HASSAN AL-AMIR"));
+    }
+
+    #[test]
+    fn whole_string_roman_numerals() {
+        assert_eq!("John Doe III", namecase_default("JOHN DOE III"));
+        assert_eq!("Doe IV, John", namecase_default("doe iv, john"));
+        assert_eq!("Henry VIII", namecase_default("henry viii"));
+        assert_eq!("Vi Nguyen", namecase_default("VI NGUYEN"));
+        assert_eq!("John Q Smith", namecase_default("JOHN Q SMITH"));
+    }
+
+    #[test]
+    fn whole_string_conjunctions() {
+        assert_eq!("Juan Velasquez y Garcia", namecase_default("JUAN VELASQUEZ Y GARCIA"));
+        assert_eq!("Eduardo Dato e Iradier", namecase_default("eduardo dato e iradier"));
+        assert_eq!("Velasquez y Garcia, Juan", namecase_default("VELASQUEZ Y GARCIA, JUAN"));
+        assert_eq!("John E. Smith", namecase_default("JOHN E. SMITH"));
+        assert_eq!("Y Garcia", namecase_default("Y GARCIA"));
+        assert_eq!("Garcia Y", namecase_default("GARCIA Y"));
+        assert_eq!("J Y Smith", namecase_default("J Y SMITH"));
+        assert_eq!("Smith, E Garcia", namecase_default("SMITH, E GARCIA"));
+
+        let options = NamecaseOptions { capitalize_particles: true, ..NamecaseOptions::default() };
+        assert_eq!("Juan Velasquez Y Garcia", namecase("JUAN VELASQUEZ Y GARCIA", options));
+    }
+
+    #[test]
+    fn whole_string_ij() {
+        assert_eq!("Anna IJsselstein", namecase_default("ANNA IJSSELSTEIN"));
+    }
+
+    #[test]
+    fn whole_string_options() {
+        let options = NamecaseOptions { capitalize_particles: true, ..NamecaseOptions::default() };
+        assert_eq!("Ludwig Van Beethoven", namecase("LUDWIG VAN BEETHOVEN", options));
+        assert_eq!("Muhammad Bin Salman", namecase("MUHAMMAD BIN SALMAN", options));
+
+        let options = NamecaseOptions {
+            exceptions: &["deVries", "MacHale", "Macmillan"],
+            ..NamecaseOptions::default()
+        };
+        assert_eq!("Jan deVries", namecase("JAN DEVRIES", options));
+        assert_eq!("Harold Macmillan", namecase("HAROLD MACMILLAN", options));
+        assert_eq!("Macmillan, Harold", namecase("MACMILLAN, HAROLD", options));
+        assert_eq!("Ian MacDonald", namecase("IAN MACDONALD", options));
+    }
+}
+
+#[cfg(test)]
+mod table_tests {
+    use super::*;
+
+    fn check(cases: &[(&str, &str)], options: NamecaseOptions) {
+        for &(input, expected) in cases.iter() {
+            assert_eq!(expected, namecase(input, options), "[{}]", input);
+        }
+    }
+
+    #[test]
+    fn irish() {
+        check(&[("CONAN O'BRIEN", "Conan O'Brien"),
+                ("conan o'brien", "Conan O'Brien"),
+                ("PETER O’TOOLE", "Peter O’Toole"),
+                ("SINEAD O'CONNOR-SMITH", "Sinead O'Connor-Smith"),
+                ("SEÁN Ó BRIAIN", "Seán Ó Briain"),
+                ("SEÁN Ó HAODHA", "Seán Ó hAodha"),
+                ("MÁIRE NÍ HUIGINN", "Máire Ní hUiginn"),
+                ("LIAM HUGHES", "Liam Hughes"),
+                ("O'HARA, MAUREEN", "O'Hara, Maureen")],
+              NamecaseOptions::default());
+    }
+
+    #[test]
+    fn apostrophes() {
+        check(&[("TONY D'ANGELO", "Tony D'Angelo"),
+                ("d'angelo, tony", "D'Angelo, Tony"),
+                ("MOUSSA N'DIAYE", "Moussa N'Diaye"),
+                ("Moussa N'diaye", "Moussa N'Diaye"),
+                ("YOUSSOU N'DOUR", "Youssou N'Dour"),
+                ("D'ARCY SMITH", "D'Arcy Smith")],
+              NamecaseOptions::default());
+    }
+
+    #[test]
+    fn scottish() {
+        check(&[("ANGUS MACDONALD", "Angus MacDonald"),
+                ("RONALD MCDONALD", "Ronald McDonald"),
+                ("MACMURDO", "MacMurdo"),
+                ("RICKY MACIAS", "Ricky Macias"),
+                ("MACIEJ NOWAK", "Maciej Nowak"),
+                ("ERNST MACH", "Ernst Mach"),
+                ("SCOTT FITZGERALD", "Scott Fitzgerald"),
+                ("JOHN FITZPATRICK-SMITH", "John Fitzpatrick-Smith")],
+              NamecaseOptions::default());
+    }
+
+    #[test]
+    fn fitz() {
+        let options = NamecaseOptions { capitalize_after_fitz: true, ..NamecaseOptions::default() };
+        check(&[("SCOTT FITZGERALD", "Scott FitzGerald"),
+                ("ROBERT FITZROY", "Robert FitzRoy"),
+                ("JOHN FITZPATRICK-SMITH", "John FitzPatrick-Smith"),
+                ("FITZ SMITH", "Fitz Smith")],
+              options);
+    }
+
+    #[test]
+    fn dutch() {
+        check(&[("PIET IJZERMAN", "Piet IJzerman"),
+                ("ANNA IJSSELSTEIN", "Anna IJsselstein"),
+                ("JAN VAN DER BERG", "Jan van der Berg"),
+                ("VAN DER BERG", "Van der Berg"),
+                ("VAN DER BERG, JAN", "Van der Berg, Jan"),
+                ("VAN MORRISON", "Van Morrison"),
+                ("LUDWIG VAN BEETHOVEN", "Ludwig van Beethoven")],
+              NamecaseOptions::default());
+    }
+
+    #[test]
+    fn french_and_spanish() {
+        check(&[("EUGENE DELACROIX", "Eugene Delacroix"),
+                ("JEAN DE LA CROIX", "Jean de la Croix"),
+                ("DE LA CROIX, JEAN", "De la Croix, Jean"),
+                ("OSCAR DE LA HOYA", "Oscar de la Hoya"),
+                ("JOSE DEL RIO", "Jose del Rio")],
+              NamecaseOptions::default());
+    }
+
+    #[test]
+    fn arabic() {
+        check(&[("MUHAMMAD BIN SALMAN AL SAUD", "Muhammad bin Salman Al Saud"),
+                ("ALI IBN ABI TALIB", "Ali ibn Abi Talib"),
+                ("HASSAN AL-AMIR", "Hassan al-Amir"),
+                ("BIN LADEN", "Bin Laden")],
+              NamecaseOptions::default());
+    }
+
+    #[test]
+    fn particles_capitalized() {
+        let options = NamecaseOptions { capitalize_particles: true, ..NamecaseOptions::default() };
+        check(&[("JAN VAN DER BERG", "Jan Van Der Berg"),
+                ("JEAN DE LA CROIX", "Jean De La Croix"),
+                ("MUHAMMAD BIN SALMAN", "Muhammad Bin Salman")],
+              options);
     }
 }
@@ -2,7 +2,6 @@ use super::utils::*;
 use super::surname;
 use super::namecase;
 use std::borrow::Cow;
-use std::ascii::AsciiExt;
 use phf;
 use unicode_segmentation::UnicodeSegmentation;
 
@@ -41,7 +40,7 @@ impl <'a>Iterator for NameParts<'a> {
 
     fn next(&mut self) -> Option<NamePart<'a>> {
         // Skip any leading whitespace
-        self.text = self.text.trim_left();
+        self.text = self.text.trim_start();
 
         if self.text.is_empty() {
             return None;
@@ -105,7 +104,7 @@ pub struct NamePart<'a> {
 
 impl <'a>NamePart<'a> {
 
-    pub fn all_from_text(text: &str, trust_capitalization: bool, location: Location) -> NameParts {
+    pub fn all_from_text(text: &str, trust_capitalization: bool, location: Location) -> NameParts<'_> {
         NameParts {
             text: text,
             trust_capitalization: trust_capitalization,
@@ -113,9 +112,9 @@ impl <'a>NamePart<'a> {
         }
     }
 
-    pub fn from_word(word: &str, trust_capitalization: bool, location: Location) -> NamePart {
+    pub fn from_word(word: &str, trust_capitalization: bool, location: Location) -> NamePart<'_> {
         let chars = word.chars().count();
-        let ascii = word.chars().all(|c| c.is_ascii());
+        let ascii = word.is_ascii();
 
         let category = if chars == 1 && ascii {
             Category::Initials
@@ -158,7 +157,7 @@ impl <'a>NamePart<'a> {
             Cow::Borrowed(word)
         } else {
             let might_be_particle = location == Location::Middle;
-            Cow::Owned(namecase::namecase(word, might_be_particle))
+            Cow::Owned(namecase::namecase_word(word, might_be_particle))
         };
 
         NamePart {
@@ -1,7 +1,16 @@
 use std::borrow::Cow;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::io::{BufRead, BufReader, Read};
 use std::iter;
+use std::slice;
 use phf;
+#[cfg(not(target_arch = "wasm32"))]
+use rustc_serialize::json::{self, Json};
 use super::utils::*;
+use super::diminutive::{self, Language};
 
 // Returns tuple (close_char, must_precede_whitespace)
 fn expected_close_char_if_opens_nickname(c: char,
@@ -18,7 +27,7 @@ fn expected_close_char_if_opens_nickname(c: char,
         _ => None,
     };
 
-    if !close.is_none() {
+    if close.is_some() {
         // Treat, e.g., opening parens as the start of a nickname
         // regardless of where it occurs
         return close;
@@ -40,7 +49,7 @@ fn expected_close_char_if_opens_nickname(c: char,
 }
 
 fn starts_with_whitespace(text: &str) -> bool {
-    text.chars().nth(0).unwrap().is_whitespace()
+    text.chars().next().unwrap().is_whitespace()
 }
 
 fn strip_from_index(nick_start_ix: usize, prev_char: char) -> usize {
@@ -53,7 +62,7 @@ fn strip_from_index(nick_start_ix: usize, prev_char: char) -> usize {
 
 // Optimized for the case where there is no nickname, and secondarily for the
 // case where there is only one. Two or more probably means bad input.
-pub fn strip_nickname(input: &str) -> Cow<str> {
+pub fn strip_nickname(input: &str) -> Cow<'_, str> {
     let mut nick_start_ix = None;
     let mut nick_open_char = '\0';
     let mut expected_close_char = '\0';
@@ -110,42 +119,325 @@ pub fn strip_nickname(input: &str) -> Cow<str> {
     Cow::Borrowed(input)
 }
 
+// Returns the text of the first nickname that `strip_nickname` would strip,
+// if any, without the enclosing punctuation
+pub fn find_nickname(input: &str) -> Option<&str> {
+    let mut prev_char = '\0';
+
+    for (i, c) in input.char_indices() {
+        if let Some((close, must_precede_whitespace)) =
+               expected_close_char_if_opens_nickname(c, prev_char.is_whitespace()) {
+            let start = i + c.len_utf8();
+            let end = input[start..].char_indices().find(|&(j, d)| {
+                d == close &&
+                (!must_precede_whitespace ||
+                 input[start + j + d.len_utf8()..].chars().next().is_none_or(char::is_whitespace))
+            });
+
+            match end {
+                Some((j, _)) => {
+                    let nickname = input[start..start + j].trim();
+                    if nickname.chars().any(char::is_alphabetic) {
+                        return Some(nickname);
+                    }
+                }
+                None => {
+                    if !must_precede_whitespace {
+                        // Unmatched opening parens, etc, run to the end of the input
+                        let nickname = input[start..].trim();
+                        if nickname.chars().any(char::is_alphabetic) {
+                            return Some(nickname);
+                        }
+                    }
+                }
+            }
+        }
+
+        prev_char = c;
+    }
+
+    None
+}
+
+/// A table of nicknames and the formal names they may stand for, used in
+/// comparison in addition to, or instead of, the built-in English-language
+/// table (see `ComparisonOptions`).
+///
+/// Names are compared ignoring case and accents.
+///
+/// # Examples
+///
+/// ```
+/// use human_name::{Name, NicknameDb, ComparisonOptions};
+///
+/// let mut db = NicknameDb::new();
+/// db.add("Beppe", "Giuseppe");
+///
+/// let options = ComparisonOptions { nicknames: Some(&db), ..ComparisonOptions::default() };
+/// let beppe = Name::parse("Beppe Verdi").unwrap();
+/// let giuseppe = Name::parse("Giuseppe Verdi").unwrap();
+///
+/// assert!(beppe.consistent_with_options(&giuseppe, &options));
+/// assert!(!beppe.consistent_with(&giuseppe));
+/// ```
+#[derive(Clone, Debug)]
+pub struct NicknameDb {
+    formal_names_by_nickname: HashMap<String, Vec<String>>,
+    include_builtins: bool,
+}
+
+/// An error loading a `NicknameDb` from CSV or JSON.
+#[derive(Debug)]
+pub enum NicknameDbError {
+    /// Reading the input failed
+    Io(io::Error),
+    /// The input was not valid JSON
+    #[cfg(not(target_arch = "wasm32"))]
+    Json(json::ParserError),
+    /// The input was well-formed, but not in the expected structure; for CSV,
+    /// includes the (1-based) line number
+    Format(String),
+}
+
+impl fmt::Display for NicknameDbError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            NicknameDbError::Io(ref e) => write!(f, "error reading nicknames: {}", e),
+            #[cfg(not(target_arch = "wasm32"))]
+            NicknameDbError::Json(ref e) => write!(f, "invalid nickname JSON: {}", e),
+            NicknameDbError::Format(ref message) => write!(f, "invalid nicknames: {}", message),
+        }
+    }
+}
+
+impl Error for NicknameDbError {
+    fn description(&self) -> &str {
+        match *self {
+            NicknameDbError::Io(_) => "error reading nicknames",
+            #[cfg(not(target_arch = "wasm32"))]
+            NicknameDbError::Json(_) => "invalid nickname JSON",
+            NicknameDbError::Format(_) => "invalid nicknames",
+        }
+    }
+}
+
+impl From<io::Error> for NicknameDbError {
+    fn from(e: io::Error) -> NicknameDbError {
+        NicknameDbError::Io(e)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl From<json::ParserError> for NicknameDbError {
+    fn from(e: json::ParserError) -> NicknameDbError {
+        NicknameDbError::Json(e)
+    }
+}
+
+// Keys and values are stored as the built-in tables are, in capitalized ASCII
+fn normalize_entry(name: &str) -> String {
+    to_ascii(&capitalize_and_normalize(name.trim())).into_owned()
+}
+
+impl NicknameDb {
+    /// An empty table which is merged with the built-in nicknames
+    pub fn new() -> NicknameDb {
+        NicknameDb {
+            formal_names_by_nickname: HashMap::new(),
+            include_builtins: true,
+        }
+    }
+
+    /// An empty table which replaces the built-in nicknames, so that only
+    /// entries added to it (and general nickname patterns such as shared
+    /// prefixes and diminutive endings) are considered
+    pub fn without_builtins() -> NicknameDb {
+        NicknameDb {
+            formal_names_by_nickname: HashMap::new(),
+            include_builtins: false,
+        }
+    }
+
+    /// Whether the built-in nicknames are considered as well as this table's
+    pub fn includes_builtins(&self) -> bool {
+        self.include_builtins
+    }
+
+    /// Records that `nickname` may stand for `formal_name`
+    pub fn add(&mut self, nickname: &str, formal_name: &str) {
+        let nickname = normalize_entry(nickname);
+        let formal_name = normalize_entry(formal_name);
+        if nickname.is_empty() || formal_name.is_empty() || nickname == formal_name {
+            return;
+        }
+
+        let names = self.formal_names_by_nickname.entry(nickname).or_default();
+        if !names.contains(&formal_name) {
+            names.push(formal_name);
+        }
+    }
+
+    /// Adds entries from CSV input, one nickname per line followed by the
+    /// formal names it may stand for, e.g. `Beppe,Giuseppe`. Blank lines and
+    /// lines starting with `#` are ignored.
+    pub fn load_csv<R: Read>(&mut self, reader: R) -> Result<(), NicknameDbError> {
+        for (i, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let mut fields = line.split(',').map(str::trim);
+            let nickname = fields.next().unwrap();
+            let formal_names: Vec<&str> = fields.filter(|f| !f.is_empty()).collect();
+            if nickname.is_empty() || formal_names.is_empty() {
+                return Err(NicknameDbError::Format(format!("line {}: expected a nickname \
+                                                            and at least one formal name",
+                                                           i + 1)));
+            }
+
+            for formal_name in formal_names {
+                self.add(nickname, formal_name);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds entries from a JSON object mapping each nickname to a formal name
+    /// or an array of formal names, e.g. `{"Beppe": ["Giuseppe"]}`.
+    ///
+    /// Not available under WebAssembly.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_json<R: Read>(&mut self, mut reader: R) -> Result<(), NicknameDbError> {
+        let json = Json::from_reader(&mut reader)?;
+        let object = match json {
+            Json::Object(object) => object,
+            _ => return Err(NicknameDbError::Format("expected a JSON object".to_string())),
+        };
+
+        for (nickname, value) in object.iter() {
+            match *value {
+                Json::String(ref formal_name) => self.add(nickname, formal_name),
+                Json::Array(ref formal_names) => {
+                    for formal_name in formal_names {
+                        match formal_name.as_string() {
+                            Some(formal_name) => self.add(nickname, formal_name),
+                            None => {
+                                return Err(NicknameDbError::Format(format!("{}: expected \
+                                                                            strings",
+                                                                           nickname)))
+                            }
+                        }
+                    }
+                }
+                _ => {
+                    return Err(NicknameDbError::Format(format!("{}: expected a string or \
+                                                                array",
+                                                               nickname)))
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Formal names recorded in this table for a nickname (not including
+    /// built-in entries)
+    pub fn formal_names(&self, nickname: &str) -> &[String] {
+        match self.formal_names_by_nickname.get(&normalize_entry(nickname)) {
+            Some(names) => names,
+            None => &[],
+        }
+    }
+
+    /// Known nicknames and diminutives of a formal name, in alphabetical
+    /// order: those from this table and (unless it replaces them) the
+    /// built-in ones, forms built from known nickname prefixes ("Betsy" for
+    /// "Elizabeth"), and regular diminutives ("Juanita" for "Juana", "Jackie"
+    /// for "Jack"). Unlike the crate-level `nicknames_of`, which only borrows
+    /// from the built-in table, this allocates each nickname.
+    pub fn nicknames_of(&self, name: &str) -> Vec<String> {
+        nicknames_in(name, Some(self))
+    }
+
+    /// Formal names which a nickname or diminutive may stand for, in
+    /// alphabetical order: the inverse of `NicknameDb::nicknames_of`.
+    pub fn formal_names_for(&self, nickname: &str) -> Vec<String> {
+        formal_names_in(nickname, Some(self))
+    }
+
+    fn normalized_formal_names(&self, nickname: &str) -> &[String] {
+        match self.formal_names_by_nickname.get(nickname) {
+            Some(names) => names,
+            None => &[],
+        }
+    }
+}
+
+impl Default for NicknameDb {
+    fn default() -> NicknameDb {
+        NicknameDb::new()
+    }
+}
+
 struct NameVariants<'a> {
     original: &'a str,
     direct_variants: Option<&'a phf::Set<&'static str>>,
     prefix_variants: Option<&'a phf::Set<&'static str>>,
+    custom_variants: &'a [String],
 }
 
 impl <'a>NameVariants<'a> {
-    pub fn for_name(name: &'a str) -> NameVariants<'a> {
+    pub fn for_name(name: &'a str, db: Option<&'a NicknameDb>) -> NameVariants<'a> {
+        let use_builtins = db.map(|db| db.include_builtins).unwrap_or(true);
+
         NameVariants {
             original: name,
-            direct_variants: NAMES_BY_IRREGULAR_NICK.get(name),
-            prefix_variants: {
-                if name.len() >= 4 && (name.ends_with("ie") || name.ends_with("ey")) {
-                    NAMES_BY_NICK_PREFIX.get(&name[0..name.len() - 2])
-                } else if name.len() >= 3 && name.ends_with('y') {
-                    NAMES_BY_NICK_PREFIX.get(&name[0..name.len() - 1])
-                } else {
-                    None
-                }
+            direct_variants: if use_builtins {
+                NAMES_BY_IRREGULAR_NICK.get(name)
+            } else {
+                None
+            },
+            prefix_variants: if use_builtins {
+                names_by_nick_prefix(name)
+            } else {
+                None
+            },
+            custom_variants: match db {
+                Some(db) => db.normalized_formal_names(name),
+                None => &[],
             },
         }
     }
 
-    pub fn iter_with_original(&self) -> NameVariantIter {
+    pub fn iter_with_original(&self) -> NameVariantIter<'_> {
         NameVariantIter {
             original: iter::once(self.original),
             direct_variants: self.direct_variants.map(|names| names.iter()),
             prefix_variants: self.prefix_variants.map(|names| names.iter()),
+            custom_variants: self.custom_variants.iter(),
         }
     }
 }
 
+// Formal names for a nickname formed from a known prefix ("Betsy", "Betsie")
+fn names_by_nick_prefix(name: &str) -> Option<&'static phf::Set<&'static str>> {
+    if name.len() >= 4 && (name.ends_with("ie") || name.ends_with("ey")) {
+        NAMES_BY_NICK_PREFIX.get(&name[0..name.len() - 2])
+    } else if name.len() >= 3 && name.ends_with('y') {
+        NAMES_BY_NICK_PREFIX.get(&name[0..name.len() - 1])
+    } else {
+        None
+    }
+}
+
 struct NameVariantIter<'a> {
     original: iter::Once<&'a str>,
     direct_variants: Option<phf::set::Iter<'a, &'static str>>,
     prefix_variants: Option<phf::set::Iter<'a, &'static str>>,
+    custom_variants: slice::Iter<'a, String>,
 }
 
 impl <'a>Iterator for NameVariantIter<'a> {
@@ -168,24 +460,224 @@ impl <'a>Iterator for NameVariantIter<'a> {
             }
         }
 
-        None
+        self.custom_variants.next().map(|name| &**name)
+    }
+}
+
+/// Known nicknames of a formal name from the built-in table of irregular
+/// nicknames ("Bess" and "Betty" for "Elizabeth"), in no particular order.
+///
+/// The nicknames are borrowed from the table, so this is cheap enough for
+/// expanding every query. Comparison also recognizes nicknames built from
+/// known prefixes ("Betsy"), regular diminutives ("Juanita" for "Juana") and
+/// many variants which can't be enumerated (e.g. prefixes of the name); to
+/// include the ones which can, use `NicknameDb::nicknames_of`.
+///
+/// # Examples
+///
+/// ```
+/// use human_name::nicknames_of;
+///
+/// let nicknames: Vec<&str> = nicknames_of("William").collect();
+/// assert!(nicknames.contains(&"Bill"));
+/// assert!(nicknames.contains(&"Wilhelm"));
+/// ```
+pub fn nicknames_of(name: &str) -> impl Iterator<Item = &'static str> {
+    let name = normalize_entry(name);
+
+    NAMES_BY_IRREGULAR_NICK.entries().filter_map(move |(nickname, names)| {
+        if names.contains(&*name) && *nickname != name {
+            Some(*nickname)
+        } else {
+            None
+        }
+    })
+}
+
+/// Formal names in the built-in tables which a nickname or diminutive may
+/// stand for, in alphabetical order. The inverse of `nicknames_of`, but
+/// also covering nicknames built from known prefixes ("Betsy") and regular
+/// diminutives of known names ("Kenny").
+///
+/// As with `nicknames_of`, the names are borrowed from the tables; use
+/// `NicknameDb::formal_names_for` to also include formal names which are
+/// only guessed from a diminutive ending ("Juanita" for "Juana").
+///
+/// # Examples
+///
+/// ```
+/// use human_name::formal_names_for;
+///
+/// assert_eq!(vec!["William"], formal_names_for("Bill").collect::<Vec<_>>());
+/// assert!(formal_names_for("Kenny").any(|name| name == "Kenneth"));
+/// assert!(formal_names_for("Bess").any(|name| name == "Elizabeth"));
+/// assert!(formal_names_for("Betsy").any(|name| name == "Elizabeth"));
+/// ```
+pub fn formal_names_for(nickname: &str) -> impl Iterator<Item = &'static str> {
+    let nickname = normalize_entry(nickname);
+    let mut result: Vec<&'static str> = Vec::new();
+
+    let direct_variants = NAMES_BY_IRREGULAR_NICK.get(&*nickname);
+    for names in direct_variants.into_iter().chain(names_by_nick_prefix(&nickname)) {
+        result.extend(names.iter().cloned());
+    }
+
+    // Reverse the regular diminutives in `matches_without_diminutive`, for
+    // formal names we know, e.g. "Kenny" for "Kenneth"
+    let known_names = NAMES_BY_IRREGULAR_NICK.values().chain(NAMES_BY_NICK_PREFIX.values());
+    for names in known_names {
+        for name in names.iter() {
+            if matches_without_diminutive(&nickname, name) {
+                result.push(name);
+            }
+        }
+    }
+
+    result.sort();
+    result.dedup();
+    result.retain(|name| *name != nickname);
+    result.into_iter()
+}
+
+fn nicknames_in(name: &str, db: Option<&NicknameDb>) -> Vec<String> {
+    let name = normalize_entry(name);
+    let mut result = Vec::new();
+
+    if db.map(|db| db.include_builtins).unwrap_or(true) {
+        result.extend(nicknames_of(&name).map(|nickname| nickname.to_string()));
+
+        // See `names_by_nick_prefix` for the endings these prefixes take
+        for (prefix, names) in NAMES_BY_NICK_PREFIX.entries() {
+            if names.contains(&*name) {
+                result.push(format!("{}y", prefix));
+                result.push(format!("{}ie", prefix));
+            }
+        }
+    }
+
+    if let Some(db) = db {
+        for (nickname, names) in db.formal_names_by_nickname.iter() {
+            if names.contains(&name) {
+                result.push(nickname.clone());
+            }
+        }
+    }
+
+    result.extend(diminutives_of(&name));
+
+    result.sort();
+    result.dedup();
+    result.retain(|nickname| *nickname != name);
+    result
+}
+
+fn formal_names_in(nickname: &str, db: Option<&NicknameDb>) -> Vec<String> {
+    let nickname = normalize_entry(nickname);
+    let mut result = Vec::new();
+
+    if db.map(|db| db.include_builtins).unwrap_or(true) {
+        result.extend(formal_names_for(&nickname).map(|name| name.to_string()));
     }
+
+    if let Some(db) = db {
+        result.extend(db.normalized_formal_names(&nickname).iter().cloned());
+    }
+
+    if nickname.len() > 5 {
+        let stem = &nickname[0..nickname.len() - 3];
+        if nickname.ends_with("ita") || nickname.ends_with("ina") {
+            result.push(format!("{}a", stem));
+        } else if nickname.ends_with("ito") {
+            result.push(format!("{}o", stem));
+        }
+    }
+
+    result.sort();
+    result.dedup();
+    result.retain(|name| *name != nickname);
+    result
+}
+
+// Regular diminutives which `matches_without_diminutive` (or the other
+// variant rules) would recognize
+fn diminutives_of(name: &str) -> Vec<String> {
+    let mut result = Vec::new();
+    let last = match name.chars().next_back() {
+        Some(c) => c,
+        None => return result,
+    };
+
+    let already_diminutive = name.ends_with("ita") || name.ends_with("ina") ||
+                             name.ends_with("ito");
+    let stem = &name[0..name.len() - last.len_utf8()];
+    if name.len() > 3 && (last == 'a' || last == 'o') && !already_diminutive &&
+       starts_with_consonant(&stem[stem.len() - 1..]) {
+        if last == 'a' {
+            result.push(format!("{}ita", stem));
+            result.push(format!("{}ina", stem));
+        } else {
+            result.push(format!("{}ito", stem));
+        }
+    } else if name.len() > 2 && name.len() <= 5 && last != 'y' &&
+              starts_with_consonant(&name[name.len() - 1..]) {
+        // Only short names take these endings directly, e.g. "Jackie"
+        result.push(format!("{}y", name));
+        result.push(format!("{}ie", name));
+
+        // Double a single final consonant after a single vowel, e.g. "Sammy"
+        let before_last = name[0..name.len() - 1].chars().next_back().unwrap();
+        if name.len() <= 4 && !starts_with_consonant(&before_last.to_string()) &&
+           !"wxy".contains(last) {
+            result.push(format!("{}{}y", name, last));
+            result.push(format!("{}{}ie", name, last));
+        }
+    }
+
+    result.retain(|diminutive| have_matching_variants(diminutive, name));
+    result
+}
+
+// Whether we recognize the (ASCII, capitalized) name from our nickname tables
+pub fn is_known_given_name(name: &str) -> bool {
+    if NAMES_BY_IRREGULAR_NICK.contains_key(name) || NAMES_BY_NICK_PREFIX.contains_key(name) {
+        return true;
+    }
+
+    if NameVariants::for_name(name, None).prefix_variants.is_some() {
+        return true;
+    }
+
+    NAMES_BY_IRREGULAR_NICK.values()
+                           .chain(NAMES_BY_NICK_PREFIX.values())
+                           .any(|names| names.contains(name))
 }
 
 pub fn have_matching_variants(original_a: &str, original_b: &str) -> bool {
+    have_matching_variants_in(original_a, original_b, None, &[])
+}
+
+// As `have_matching_variants`, but consulting a custom table (which may or may
+// not also include the built-in tables), and recognizing diminutives in the
+// given languages
+pub fn have_matching_variants_in(original_a: &str,
+                                 original_b: &str,
+                                 db: Option<&NicknameDb>,
+                                 languages: &[Language])
+                                 -> bool {
     let original_a = to_ascii(original_a);
     let original_b = to_ascii(original_b);
 
-    let a_variants = NameVariants::for_name(&*original_a);
-    let b_variants = NameVariants::for_name(&*original_b);
+    let a_variants = NameVariants::for_name(&*original_a, db);
+    let b_variants = NameVariants::for_name(&*original_b, db);
 
     a_variants.iter_with_original()
-              .any(|a| b_variants.iter_with_original().any(|b| variants_match(a, b)))
+              .any(|a| b_variants.iter_with_original().any(|b| variants_match(a, b, languages)))
 }
 
-fn variants_match(a: &str, b: &str) -> bool {
+fn variants_match(a: &str, b: &str, languages: &[Language]) -> bool {
     have_prefix_match(a, b) || is_final_syllables_of(a, b) || is_final_syllables_of(b, a) ||
-    matches_without_diminutive(a, b) || matches_without_diminutive(b, a)
+    matches_without_diminutive(a, b) || matches_without_diminutive(b, a) ||
+    diminutive::is_diminutive_of(a, b, languages) || diminutive::is_diminutive_of(b, a, languages)
 }
 
 fn have_prefix_match(a: &str, b: &str) -> bool {
@@ -242,6 +734,115 @@ fn is_final_syllables_of(needle: &str, haystack: &str) -> bool {
 mod tests {
     use super::*;
 
+    #[test]
+    fn custom_nicknames() {
+        let mut db = NicknameDb::new();
+        db.add("beppe", "GIUSEPPE");
+        db.add("Peppino", "Giuseppe");
+
+        assert_eq!(&["Giuseppe".to_string()], db.formal_names("Beppe"));
+        assert!(have_matching_variants_in("Beppe", "Giuseppe", Some(&db), &[]));
+        assert!(have_matching_variants_in("Giuseppe", "Beppe", Some(&db), &[]));
+        assert!(have_matching_variants_in("Beppe", "Peppino", Some(&db), &[]));
+        assert!(have_matching_variants_in("Bill", "William", Some(&db), &[]));
+        assert!(!have_matching_variants("Beppe", "Giuseppe"));
+    }
+
+    #[test]
+    fn custom_nicknames_without_builtins() {
+        let mut db = NicknameDb::without_builtins();
+        db.add("Beppe", "Giuseppe");
+
+        assert!(have_matching_variants_in("Beppe", "Giuseppe", Some(&db), &[]));
+        assert!(!have_matching_variants_in("Bill", "William", Some(&db), &[]));
+    }
+
+    #[test]
+    fn load_csv() {
+        let csv = "# Italian\nBeppe, Giuseppe\n\nGigi,Luigi,Pierluigi\n";
+        let mut db = NicknameDb::new();
+        db.load_csv(csv.as_bytes()).unwrap();
+
+        assert_eq!(&["Luigi".to_string(), "Pierluigi".to_string()], db.formal_names("Gigi"));
+        assert!(have_matching_variants_in("Gigi", "Pierluigi", Some(&db), &[]));
+
+        let mut db = NicknameDb::new();
+        match db.load_csv("Beppe,Giuseppe\nGigi\n".as_bytes()) {
+            Err(NicknameDbError::Format(message)) => assert!(message.starts_with("line 2")),
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_json() {
+        let json = r#"{"Franzi": ["Franziska", "Franz"], "Sepp": "Josef"}"#;
+        let mut db = NicknameDb::new();
+        db.load_json(json.as_bytes()).unwrap();
+
+        assert!(have_matching_variants_in("Sepp", "Josef", Some(&db), &[]));
+        assert!(have_matching_variants_in("Franzi", "Franziska", Some(&db), &[]));
+
+        let mut db = NicknameDb::new();
+        assert!(db.load_json("[]".as_bytes()).is_err());
+        assert!(db.load_json("{".as_bytes()).is_err());
+        assert!(db.load_json(r#"{"Sepp": 1}"#.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn nicknames_and_formal_names() {
+        let nicknames: Vec<&str> = nicknames_of("Elizabeth").collect();
+        for nickname in ["Bess", "Liz"].iter() {
+            assert!(nicknames.contains(nickname), "{}", nickname);
+        }
+        for nickname in nicknames.iter() {
+            assert!(have_matching_variants(nickname, "Elizabeth"), "{}", nickname);
+        }
+
+        assert!(nicknames_of("pedro").next().is_none());
+        assert!(formal_names_for("Betsy").any(|name| name == "Elizabeth"));
+        assert!(formal_names_for("Pedrito").next().is_none());
+        assert!(formal_names_for("Zzyzx").next().is_none());
+    }
+
+    #[test]
+    fn generated_nicknames_and_formal_names() {
+        let db = NicknameDb::new();
+
+        let nicknames = db.nicknames_of("Elizabeth");
+        for nickname in ["Bess", "Betsy", "Liz"].iter() {
+            assert!(nicknames.contains(&nickname.to_string()), "{}", nickname);
+        }
+        for nickname in nicknames.iter() {
+            assert!(have_matching_variants(nickname, "Elizabeth"), "{}", nickname);
+        }
+
+        assert_eq!(vec!["Pedrito"], db.nicknames_of("pedro"));
+        assert!(db.nicknames_of("Sam").contains(&"Sammy".to_string()));
+        assert!(db.formal_names_for("Betsy").contains(&"Elizabeth".to_string()));
+        assert_eq!(vec!["Pedro"], db.formal_names_for("Pedrito"));
+        assert_eq!(vec!["Juana"], db.formal_names_for("Juanita"));
+        assert!(db.formal_names_for("Zzyzx").is_empty());
+    }
+
+    #[test]
+    fn nicknames_in_custom_db() {
+        let mut db = NicknameDb::without_builtins();
+        db.add("Beppe", "Giuseppe");
+
+        assert_eq!(vec!["Beppe"], db.nicknames_of("Giuseppe"));
+        assert_eq!(vec!["Giuseppe"], db.formal_names_for("Beppe"));
+        assert!(db.formal_names_for("Bill").is_empty());
+    }
+
+    #[test]
+    fn known_given_names() {
+        assert!(is_known_given_name("Jane"));
+        assert!(is_known_given_name("Bill"));
+        assert!(is_known_given_name("Elizabeth"));
+        assert!(!is_known_given_name("Customer"));
+    }
+
     #[test]
     fn nick_and_name() {
         assert!(have_matching_variants("Dave", "David"));
@@ -328,6 +929,24 @@ mod tests {
     fn unspaced_quotes() {
         assert_eq!("Ro'bert R'oberts", strip_nickname("Ro'bert R'oberts"));
     }
+
+    #[test]
+    fn find_nothing() {
+        assert_eq!(None, find_nickname("Robert Roberts"));
+        assert_eq!(None, find_nickname("Ro'bert R'oberts"));
+        assert_eq!(None, find_nickname("Robert Mr. Bob' Roberts"));
+    }
+
+    #[test]
+    fn find_parens() {
+        assert_eq!(Some("Mr. Bob"), find_nickname("Robert (Mr. Bob) Roberts"));
+        assert_eq!(Some("Mr. Bob"), find_nickname("Robert (Mr. Bob"));
+    }
+
+    #[test]
+    fn find_quotes() {
+        assert_eq!(Some("Bob"), find_nickname("Robert 'Bob' Roberts"));
+    }
 }
 
 // There's no reason not to just use arrays for the values except that it won't compile :(
@@ -0,0 +1,343 @@
+use phf;
+use super::Name;
+use super::title;
+use super::utils::is_mixed_case;
+
+/// What sort of entity a string appears to name (see `classify`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    /// A single person, e.g. "Jane Doe"
+    Person,
+    /// A company or other organization, e.g. "Smith & Sons LLC"
+    Organization,
+    /// Two people sharing a name, e.g. "Mr. & Mrs. John Doe"
+    Couple,
+    /// Nothing we recognize, e.g. "Customer Service" or "foo@bar.com"
+    Unknown,
+}
+
+// Lowercase, without periods, for comparison against normalized words
+static ORGANIZATION_SUFFIXES: phf::Set<&'static str> = phf_set! {
+    "aps",
+    "asa",
+    "bhd",
+    "company",
+    "corp",
+    "corporation",
+    "gmbh",
+    "inc",
+    "incorporated",
+    "kft",
+    "limited",
+    "llc",
+    "llp",
+    "ltd",
+    "ltda",
+    "oyj",
+    "plc",
+    "pte",
+    "pty",
+    "sarl",
+    "sdn",
+    "srl",
+};
+
+// Suffixes which are also plausible surnames or name words ("Zaldy Co"), so
+// we only take them as suffixes when punctuated ("Co.", "S.A.", "A/S"),
+// capitalized in otherwise mixed-case input ("Volvo AB"), or alongside some
+// other sign of an organization
+static AMBIGUOUS_ORGANIZATION_SUFFIXES: phf::Set<&'static str> = phf_set! {
+    "ab",
+    "ag",
+    "as",
+    "bv",
+    "co",
+    "cv",
+    "kg",
+    "kk",
+    "lp",
+    "nv",
+    "oy",
+    "sa",
+    "sas",
+    "spa",
+};
+
+// Words which (almost) never appear in a person's name, but often in the name
+// of an organization (lowercase)
+static ORGANIZATION_KEYWORDS: phf::Set<&'static str> = phf_set! {
+    "academy",
+    "agency",
+    "associates",
+    "association",
+    "authority",
+    "brothers",
+    "bros",
+    "clinic",
+    "college",
+    "committee",
+    "consulting",
+    "council",
+    "department",
+    "enterprises",
+    "foundation",
+    "group",
+    "holdings",
+    "hospital",
+    "industries",
+    "institute",
+    "international",
+    "laboratories",
+    "laboratory",
+    "ministry",
+    "museum",
+    "partners",
+    "partnership",
+    "society",
+    "solutions",
+    "sons",
+    "systems",
+    "technologies",
+    "university",
+    "universität",
+    "université",
+    "universidad",
+};
+
+// Organizational words which are also surnames ("Charlotte Church", "Frank
+// Bank"), so only count in an organizational pattern (see
+// `has_organization_keyword`)
+static SURNAME_LIKE_ORGANIZATION_KEYWORDS: phf::Set<&'static str> = phf_set! {
+    "bank",
+    "center",
+    "centre",
+    "church",
+    "club",
+    "fund",
+    "school",
+    "trust",
+};
+
+// Words which make a surname-like keyword organizational, as in "First
+// National Bank" or "Community Church"
+static ORGANIZATION_MODIFIERS: phf::Set<&'static str> = phf_set! {
+    "american",
+    "central",
+    "citizens",
+    "city",
+    "community",
+    "county",
+    "federal",
+    "first",
+    "global",
+    "mutual",
+    "national",
+    "peoples",
+    "royal",
+    "savings",
+    "state",
+    "united",
+};
+
+static GENERATIONAL_SUFFIXES: [&'static str; 5] = ["jr", "sr", "ii", "iii", "iv"];
+
+// Lowercase letters only, so "L.L.C." or "GmbH" compare as "llc" or "gmbh"
+pub fn normalize(word: &str) -> String {
+    word.chars().filter(|c| c.is_alphabetic()).flat_map(char::to_lowercase).collect()
+}
+
+// Words of the input as written, and normalized (except "&")
+fn split_words(input: &str) -> Vec<(&str, String)> {
+    input.split(|c: char| c.is_whitespace() || c == ',')
+         .map(|w| (w, if w == "&" { w.to_string() } else { normalize(w) }))
+         .filter(|(_, w)| !w.is_empty())
+         .collect()
+}
+
+// Does the input end with a corporate suffix, e.g. "Acme Widgets, Inc." or
+// "Maersk A/S"?
+pub fn ends_with_organization_suffix(input: &str) -> bool {
+    let words = split_words(input);
+
+    match words.last() {
+        Some(&(raw, ref word)) => {
+            if ORGANIZATION_SUFFIXES.contains(&**word) {
+                true
+            } else if AMBIGUOUS_ORGANIZATION_SUFFIXES.contains(&**word) {
+                raw.contains('.') || raw.contains('/') ||
+                (is_mixed_case(input) && !raw.chars().any(char::is_lowercase)) ||
+                words.iter().any(|(_, w)| w == "&" || w == "and") ||
+                has_organization_keyword(input)
+            } else {
+                false
+            }
+        }
+        None => false,
+    }
+}
+
+// Does the input contain an organizational keyword, e.g. "University of
+// Michigan" or "First National Bank"?
+//
+// Keywords which are also surnames only count when they're used like an
+// organization's name ("Bank of America", "The Trust for Public Land",
+// "First National Bank", "Deutsche Bank AG"), not in the position of a given
+// name or surname ("Charlotte Church", "Church, Frank", "Trust Mabaso").
+pub fn has_organization_keyword(input: &str) -> bool {
+    let words: Vec<String> = split_words(input).into_iter().map(|(_, w)| w).collect();
+
+    if words.iter().any(|w| ORGANIZATION_KEYWORDS.contains(&**w)) {
+        return true;
+    }
+
+    let last_name_index = words.iter()
+                               .rposition(|w| !GENERATIONAL_SUFFIXES.contains(&&**w))
+                               .unwrap_or(0);
+
+    words.iter().enumerate().any(|(i, word)| {
+        if !SURNAME_LIKE_ORGANIZATION_KEYWORDS.contains(&**word) {
+            return false;
+        }
+
+        let followed_by_preposition = words.get(i + 1)
+                                           .map(|w| w == "of" || w == "for")
+                                           .unwrap_or(false);
+        // The first word is a given name, or a surname before a comma
+        let in_name_position = i == 0 || i == last_name_index;
+
+        followed_by_preposition || !in_name_position || words[0] == "the" ||
+        words.iter().any(|w| ORGANIZATION_MODIFIERS.contains(&**w))
+    })
+}
+
+/// Guesses whether the input names a person, an organization, or a couple.
+///
+/// Uses built-in tables of corporate suffixes (e.g. "LLC", "GmbH") and
+/// organizational keywords (e.g. "University", "Foundation"), treats
+/// ampersands and "and" between given names or titles as couples, and
+/// otherwise considers input a person if `Name::looks_like_person_name`.
+///
+/// # Examples
+///
+/// ```
+/// use human_name::{classify, Kind};
+///
+/// assert_eq!(Kind::Person, classify("Jane Doe"));
+/// assert_eq!(Kind::Organization, classify("Smith & Sons LLC"));
+/// assert_eq!(Kind::Organization, classify("University of Michigan"));
+/// assert_eq!(Kind::Couple, classify("Mr. & Mrs. John Doe"));
+/// assert_eq!(Kind::Couple, classify("John and Jane Doe"));
+/// assert_eq!(Kind::Unknown, classify("Customer Service"));
+/// ```
+pub fn classify(input: &str) -> Kind {
+    let words: Vec<String> = split_words(input).into_iter().map(|(_, w)| w).collect();
+
+    if words.is_empty() {
+        return Kind::Unknown;
+    }
+
+    if ends_with_organization_suffix(input) || has_organization_keyword(input) {
+        return Kind::Organization;
+    }
+
+    if let Some(i) = words.iter().position(|w| w == "&" || w == "and") {
+        let before = &words[0..i];
+        let after = &words[i + 1..];
+
+        if before.is_empty() || after.is_empty() {
+            return Kind::Unknown;
+        }
+
+        // "Mr. & Mrs. John Doe", "John & Jane Doe", or "John Doe & Jane Roe"
+        if before.iter().all(|w| title::is_handle_title(w)) ||
+           (before.len() == 1 && after.len() > 1) || (before.len() > 1 && after.len() > 1) {
+            return Kind::Couple;
+        }
+
+        // "Smith & Wesson", "Johnson and Johnson"
+        return Kind::Organization;
+    }
+
+    if Name::looks_like_person_name(input) {
+        Kind::Person
+    } else {
+        Kind::Unknown
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::ParseOptions;
+
+    #[test]
+    fn suffixes() {
+        assert!(ends_with_organization_suffix("Acme Inc."));
+        assert!(ends_with_organization_suffix("Acme L.L.C."));
+        assert!(ends_with_organization_suffix("Siemens GmbH"));
+        assert!(!ends_with_organization_suffix("John Smith"));
+        assert!(!ends_with_organization_suffix("Co"));
+    }
+
+    #[test]
+    fn ambiguous_suffixes() {
+        assert!(ends_with_organization_suffix("Acme Co."));
+        assert!(ends_with_organization_suffix("Banco Santander, S.A."));
+        assert!(ends_with_organization_suffix("Maersk A/S"));
+        assert!(ends_with_organization_suffix("Volvo AB"));
+        assert!(ends_with_organization_suffix("Smith & Co"));
+        assert!(!ends_with_organization_suffix("Zaldy Co"));
+        assert!(!ends_with_organization_suffix("ZALDY CO"));
+        assert!(!ends_with_organization_suffix("Kim Sa"));
+        assert!(!ends_with_organization_suffix("Matti Oy"));
+    }
+
+    #[test]
+    fn organizations() {
+        assert_eq!(Kind::Organization, classify("Acme Widgets, Inc."));
+        assert_eq!(Kind::Organization, classify("Smith & Wesson"));
+        assert_eq!(Kind::Organization, classify("Johnson and Johnson"));
+        assert_eq!(Kind::Organization, classify("The Rockefeller Foundation"));
+        assert_eq!(Kind::Organization, classify("Deutsche Bank AG"));
+        assert_eq!(Kind::Organization, classify("Bank of America"));
+        assert_eq!(Kind::Organization, classify("First National Bank"));
+        assert_eq!(Kind::Organization, classify("Church of England"));
+        assert_eq!(Kind::Organization, classify("The Trust for Public Land"));
+        assert_eq!(Kind::Organization, classify("Maersk A/S"));
+    }
+
+    #[test]
+    fn couples() {
+        assert_eq!(Kind::Couple, classify("John & Jane Smith"));
+        assert_eq!(Kind::Couple, classify("Dr. and Mrs. Robert Roberts"));
+        assert_eq!(Kind::Couple, classify("Jane Doe & John Roe"));
+    }
+
+    #[test]
+    fn people() {
+        assert_eq!(Kind::Person, classify("Smith, John"));
+        assert_eq!(Kind::Person, classify("Dr. Juan Alberto T. Velasquez y Garcia III"));
+    }
+
+    #[test]
+    fn people_with_organizational_surnames() {
+        assert_eq!(Kind::Person, classify("Charlotte Church"));
+        assert_eq!(Kind::Person, classify("Frank Church"));
+        assert_eq!(Kind::Person, classify("Church, Frank"));
+        assert_eq!(Kind::Person, classify("Frank Church Jr."));
+        assert_eq!(Kind::Person, classify("Zaldy Co"));
+        assert!(classify("ZALDY CO") != Kind::Organization);
+        assert_eq!(Kind::Person, classify("Mary Trust"));
+
+        let options = ParseOptions { refuse_organizations: true, ..ParseOptions::default() };
+        assert!(Name::parse_with_options("Charlotte Church", &options).is_some());
+        assert!(Name::parse_with_options("Zaldy Co", &options).is_some());
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(Kind::Unknown, classify(""));
+        assert_eq!(Kind::Unknown, classify("Test User"));
+        assert_eq!(Kind::Unknown, classify("& Jones"));
+    }
+}
@@ -9,28 +9,39 @@ struct ParseOp<'a> {
     generation_from_suffix: Option<usize>,
     maybe_not_prefix: Option<NamePart<'a>>,
     maybe_not_postfix: Option<NamePart<'a>>,
+    titles: Titles<'a>,
     use_capitalization: bool,
 }
 
+// Words of any titles we stripped, as they appeared in the input
+pub struct Titles<'a> {
+    pub prefix: Vec<&'a str>,
+    pub postfix: Vec<&'a str>,
+}
+
 pub fn parse(name: &str,
              use_capitalization: bool)
-             -> Option<(Vec<NamePart>, usize, Option<usize>)> {
+             -> Option<(Vec<NamePart<'_>>, usize, Option<usize>, Titles<'_>)> {
     let op = ParseOp {
         surname_index: 0,
         generation_from_suffix: None,
         maybe_not_prefix: None,
         maybe_not_postfix: None,
+        titles: Titles {
+            prefix: Vec::new(),
+            postfix: Vec::new(),
+        },
         use_capitalization: use_capitalization,
     };
 
-    let (words, surname_index, generation_from_suffix) = op.run(name);
+    let (words, surname_index, generation_from_suffix, titles) = op.run(name);
 
     let successful = words.len() >= 2 && words.iter().all(|w| w.is_namelike() || w.is_initials()) &&
                      surname_index > 0 && surname_index < 6 &&
                      words[surname_index..].iter().any(|w| w.is_namelike());
 
     if successful {
-        Some((words, surname_index, generation_from_suffix))
+        Some((words, surname_index, generation_from_suffix, titles))
     } else {
         None
     }
@@ -38,7 +49,7 @@ pub fn parse(name: &str,
 
 impl <'a>ParseOp<'a> {
 
-    fn run(mut self, name: &'a str) -> (Vec<NamePart<'a>>, usize, Option<usize>) {
+    fn run(mut self, name: &'a str) -> (Vec<NamePart<'a>>, usize, Option<usize>, Titles<'a>) {
         let mut words: Vec<NamePart> = Vec::with_capacity(2);
 
         // Separate comma-separated titles and suffixes, then flip remaining words
@@ -67,9 +78,13 @@ impl <'a>ParseOp<'a> {
         // if they are comma-separated, and we already handled that case)
         if ParseOp::fixably_invalid(&words, self.surname_index) {
             if self.maybe_not_postfix.is_some() {
-                words.push(self.maybe_not_postfix.unwrap());
+                let word = self.maybe_not_postfix.unwrap();
+                ParseOp::unrecord_title(&mut self.titles.postfix, word.word);
+                words.push(word);
             } else if self.maybe_not_prefix.is_some() {
-                words.insert(0, self.maybe_not_prefix.unwrap());
+                let word = self.maybe_not_prefix.unwrap();
+                ParseOp::unrecord_title(&mut self.titles.prefix, word.word);
+                words.insert(0, word);
             }
         }
 
@@ -91,6 +106,9 @@ impl <'a>ParseOp<'a> {
                     break;
                 }
             }
+
+            // These precede any postfix titles we've already found
+            self.titles.postfix.insert(0, removed.word);
         }
 
         // Handle case where we thought the whole before-comma part was a surname,
@@ -100,10 +118,16 @@ impl <'a>ParseOp<'a> {
             self.surname_index = surname::find_surname_index(&words[1..]) + 1;
         }
 
-        (words, self.surname_index, self.generation_from_suffix)
+        (words, self.surname_index, self.generation_from_suffix, self.titles)
+    }
+
+    fn unrecord_title(titles: &mut Vec<&'a str>, word: &'a str) {
+        if let Some(i) = titles.iter().rposition(|title| *title == word) {
+            titles.remove(i);
+        }
     }
 
-    fn fixably_invalid(words: &Vec<NamePart>, surname_index: usize) -> bool {
+    fn fixably_invalid(words: &[NamePart], surname_index: usize) -> bool {
         words.len() < 2 || !words[surname_index..].iter().any(|w| w.is_namelike())
     }
 
@@ -193,30 +217,18 @@ impl <'a>ParseOp<'a> {
         debug_assert!(self.surname_index > 0,
                 "Invalid state for handle_after_surname!");
 
-        if self.maybe_not_postfix.is_some() && self.generation_from_suffix.is_some() {
-            return;
-        }
-
-        let mut postfix_words = NamePart::all_from_text(part,
-                                                        self.use_capitalization,
-                                                        Location::End);
-        while self.maybe_not_postfix.is_none() || self.generation_from_suffix.is_none() {
-            match postfix_words.next() {
-                Some(word) => {
-                    if let Some(generation) = suffix::generation_from_suffix(&word, false) {
-                        self.found_suffix(word, generation);
-                    } else {
-                        self.found_postfix_title(word);
-                    }
-                }
-                None => {
-                    break;
-                }
+        // Keep going even after we've found a suffix and a possible surname,
+        // so we have a complete record of postfix titles
+        for word in NamePart::all_from_text(part, self.use_capitalization, Location::End) {
+            if let Some(generation) = suffix::generation_from_suffix(&word, false) {
+                self.found_suffix(word, generation);
+            } else {
+                self.found_postfix_title(word);
             }
         }
     }
 
-    fn strip_postfixes<'b>(&mut self, words: &mut Vec<NamePart<'a>>, after_comma: bool) {
+    fn strip_postfixes(&mut self, words: &mut Vec<NamePart<'a>>, after_comma: bool) {
         let skip = if after_comma {
             0
         } else {
@@ -225,8 +237,8 @@ impl <'a>ParseOp<'a> {
         let expect_initials = after_comma && self.surname_index == 0;
 
         let last_nonpostfix_index = words[skip..].iter().rposition(|word| {
-            suffix::generation_from_suffix(&word, expect_initials).is_none() &&
-            !title::is_postfix_title(&word, expect_initials)
+            suffix::generation_from_suffix(word, expect_initials).is_none() &&
+            !title::is_postfix_title(word, expect_initials)
         });
 
         let first_abbr_index = words[skip..]
@@ -242,17 +254,23 @@ impl <'a>ParseOp<'a> {
                                            });
 
         if first_postfix_index < words.len() {
-            while words.len() > first_postfix_index + 1 {
-                words.pop();
-            }
+            let mut postfixes = words.drain(first_postfix_index..);
 
-            let first_postfix = words.pop().unwrap();
+            let first_postfix = postfixes.next().unwrap();
             if let Some(generation) = suffix::generation_from_suffix(&first_postfix,
                                                                      expect_initials) {
                 self.found_suffix(first_postfix, generation);
             } else {
                 self.found_postfix_title(first_postfix);
             }
+
+            // We only consider the first postfix as a suffix or possible
+            // surname, but we keep a record of any other titles
+            for postfix in postfixes {
+                if suffix::generation_from_suffix(&postfix, expect_initials).is_none() {
+                    self.titles.postfix.push(postfix.word);
+                }
+            }
         }
     }
 
@@ -261,13 +279,18 @@ impl <'a>ParseOp<'a> {
             self.generation_from_suffix = Some(generation);
         }
 
-        self.found_postfix_title(suffix);
+        self.found_postfix(suffix);
     }
 
-    // We throw away most postfix titles, but keep the first one that's namelike,
-    // just in case we make a mistake and it turns out by process of elimination
-    // that this must actually be a surname
     fn found_postfix_title(&mut self, postfix: NamePart<'a>) {
+        self.titles.postfix.push(postfix.word);
+        self.found_postfix(postfix);
+    }
+
+    // We only keep the text of most postfixes, but keep the first one that's
+    // namelike, just in case we make a mistake and it turns out by process of
+    // elimination that this must actually be a surname
+    fn found_postfix(&mut self, postfix: NamePart<'a>) {
         if self.maybe_not_postfix.is_none() && (postfix.is_namelike() || postfix.is_initials()) {
             self.maybe_not_postfix = Some(postfix);
         }
@@ -275,6 +298,8 @@ impl <'a>ParseOp<'a> {
 
     // Ditto prefixes
     fn found_prefix_title(&mut self, prefix: Vec<NamePart<'a>>) {
+        self.titles.prefix.extend(prefix.iter().map(|word| word.word));
+
         if self.maybe_not_prefix.is_none() {
             if let Some(word) = prefix.into_iter()
                                       .rev()
@@ -0,0 +1,118 @@
+use super::surname;
+use super::utils::*;
+
+// Particles introducing an Arabic or Hebrew patronymic or matronymic ("son of",
+// "daughter of")
+static NASAB_PARTICLES: [&'static str; 6] = ["bin", "binti", "bint", "ibn", "ben", "bat"];
+
+// Russian, Ukrainian and Belarusian patronymic endings, in ASCII
+static EAST_SLAVIC_PATRONYMIC_ENDINGS: [&'static str; 9] =
+    ["ovich", "evich", "ovych", "evych", "yich", "ovna", "evna", "ivna", "ichna"];
+
+const MIN_PATRONYMIC_STEM_LEN: usize = 2;
+
+pub fn is_nasab_particle(word: &str) -> bool {
+    NASAB_PARTICLES.iter().any(|p| p.eq_ignore_ascii_case(word))
+}
+
+// Number of leading surname words making up a chain of patronymics, e.g. 4 for
+// "bin Salman bin Abdulaziz Al Saud".
+//
+// We only take a particle and the name after it when something is left for
+// the surname proper, so "Ahmad ben Husain" and "Osama bin Laden" keep their
+// particle as part of the surname. Nor do we split up a nasab that continues
+// with a kunya or another particle, as in "Ali ibn Abi Talib".
+pub fn nasab_chain_len(surnames: &[String]) -> usize {
+    let mut len = 0;
+
+    while len + 2 < surnames.len() && is_nasab_particle(&surnames[len]) {
+        let name = &surnames[len + 1];
+        if surname::is_particle(name) || is_nasab_particle(name) ||
+           name.eq_ignore_ascii_case("Abi") {
+            break;
+        }
+
+        len += 2;
+    }
+
+    len
+}
+
+// Is this an East Slavic middle-name patronymic, e.g. "Vladimirovich" or
+// "Ivanovna"?
+pub fn is_east_slavic_patronymic(word: &str) -> bool {
+    let lower = to_ascii(word).to_ascii_lowercase();
+
+    EAST_SLAVIC_PATRONYMIC_ENDINGS.iter().any(|ending| {
+        lower.ends_with(ending) && lower.len() >= ending.len() + MIN_PATRONYMIC_STEM_LEN
+    })
+}
+
+// Is this an Icelandic patronymic or matronymic surname, e.g. "Sigurðsson" or
+// "Guðmundsdóttir"?
+//
+// Plenty of Scandinavian and English surnames end in "-son" or "-sson", so we
+// only count those when the name also has letters that suggest Icelandic
+// ("Jón Sigurðsson", "Ólafur Ragnar Grímsson").
+pub fn is_icelandic_patronymic(word: &str, given_names: &[String]) -> bool {
+    let lower = to_ascii(word).to_ascii_lowercase();
+
+    if lower.ends_with("dottir") && lower.len() >= "dottir".len() + MIN_PATRONYMIC_STEM_LEN {
+        return true;
+    }
+
+    if !lower.ends_with("sson") || lower.len() < "sson".len() + MIN_PATRONYMIC_STEM_LEN {
+        return false;
+    }
+
+    let looks_icelandic = |w: &str| {
+        w.chars().any(|c| c == 'ð' || c == 'Ð' || c == 'þ' || c == 'Þ' || c == '\u{301}')
+    };
+
+    looks_icelandic(word) || given_names.iter().any(|w| looks_icelandic(w))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unicode_normalization::UnicodeNormalization;
+
+    fn words(s: &str) -> Vec<String> {
+        s.split(' ').map(|w| w.nfkd().collect()).collect()
+    }
+
+    #[test]
+    fn nasab_chains() {
+        assert_eq!(4, nasab_chain_len(&words("Bin Salman Bin Abdulaziz Al Saud")));
+        assert_eq!(2, nasab_chain_len(&words("bat Avraham Cohen")));
+        assert_eq!(2, nasab_chain_len(&words("Ibn Muhammad Ibn Abi Talib")));
+        assert_eq!(0, nasab_chain_len(&words("Ibn Abi Talib")));
+        assert_eq!(0, nasab_chain_len(&words("Ben Husain")));
+        assert_eq!(0, nasab_chain_len(&words("Bin Laden")));
+        assert_eq!(0, nasab_chain_len(&words("Al Saud")));
+        assert_eq!(0, nasab_chain_len(&words("Bin Al Saud")));
+    }
+
+    #[test]
+    fn east_slavic_patronymics() {
+        assert!(is_east_slavic_patronymic("Vladimirovich"));
+        assert!(is_east_slavic_patronymic("Ivanovna"));
+        assert!(is_east_slavic_patronymic("Ilyich"));
+        assert!(is_east_slavic_patronymic("Tarasivna"));
+        assert!(is_east_slavic_patronymic("Mykolayovych"));
+        assert!(!is_east_slavic_patronymic("Dietrich"));
+        assert!(!is_east_slavic_patronymic("Anna"));
+        assert!(!is_east_slavic_patronymic("Ivanov"));
+    }
+
+    #[test]
+    fn icelandic_patronymics() {
+        assert!(is_icelandic_patronymic(&words("Sigurðsson")[0], &words("Jón")));
+        assert!(is_icelandic_patronymic(&words("Jónsson")[0], &words("Einar")));
+        assert!(is_icelandic_patronymic(&words("Guðmundsdóttir")[0], &words("Björk")));
+        assert!(is_icelandic_patronymic(&words("Magnusdottir")[0], &words("Anna")));
+        assert!(is_icelandic_patronymic(&words("Grimsson")[0], &words("Ólafur Ragnar")));
+        assert!(!is_icelandic_patronymic(&words("Johansson")[0], &words("Erik")));
+        assert!(!is_icelandic_patronymic(&words("Robinson")[0], &words("Jack")));
+    }
+}
@@ -0,0 +1,185 @@
+use super::Name;
+use super::nickname;
+use super::organization;
+use super::surname;
+use super::title;
+use super::utils::{is_missing_vowels, is_nonalphanumeric, to_ascii};
+
+// Score at or above which we consider an input a plausible person name
+const PLAUSIBLE_THRESHOLD: f64 = 0.5;
+
+// Words commonly used in fake, test or role-based "names" (lowercase)
+static PLACEHOLDER_WORDS: [&'static str; 30] = ["admin", "administrator", "anonymous", "asdf",
+                                                "customer", "dummy", "example", "fake", "first",
+                                                "foo", "bar", "guest", "info", "last", "name",
+                                                "none", "null", "noreply", "qwerty", "sales",
+                                                "sample", "service", "services", "staff",
+                                                "support", "team", "test", "tester", "unknown",
+                                                "user"];
+
+// Well-known placeholder names, which are real names but unlikely to be real people
+static PLACEHOLDER_NAMES: [&'static str; 6] = ["john doe", "jane doe", "joe bloggs",
+                                               "john q public", "mickey mouse", "donald duck"];
+
+impl Name {
+
+    /// Heuristically estimates how likely the input is to be a real person's
+    /// name, from 0.0 (not at all) to 1.0, for flagging fake or organizational
+    /// entries such as "Customer Service", "Acme Corp" or "Test User".
+    ///
+    /// Returns 0.0 if the input can't be parsed. Otherwise, considers whether
+    /// the given name is one we know, whether the input ends with a corporate
+    /// suffix, whether any words are titles or common placeholder words, and
+    /// whether the words look pronounceable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use human_name::Name;
+    ///
+    /// assert!(Name::plausibility("Elizabeth Bennet") > Name::plausibility("Test User"));
+    /// assert_eq!(0.0, Name::plausibility("foo@bar.com"));
+    /// ```
+    pub fn plausibility(input: &str) -> f64 {
+        let name = match Name::parse(input) {
+            Some(name) => name,
+            None => return 0.0,
+        };
+
+        let input_words: Vec<String> = input.split(|c: char| c.is_whitespace() || c == ',')
+                                            .map(organization::normalize)
+                                            .filter(|w| !w.is_empty())
+                                            .collect();
+
+        if organization::ends_with_organization_suffix(input) {
+            return 0.0;
+        }
+
+        let mut score = 0.6;
+
+        if let Some(given_name) = name.given_name() {
+            if nickname::is_known_given_name(&to_ascii(given_name)) {
+                score += 0.3;
+            }
+        }
+
+        // Some placeholder words are also surnames ("Robert Service"), so
+        // only count them in surname position if the whole input is made of
+        // them ("Customer Service")
+        let surname_words: Vec<String> = name.surnames()
+                                             .iter()
+                                             .map(|w| organization::normalize(w))
+                                             .collect();
+        let is_placeholder = |w: &String| PLACEHOLDER_WORDS.contains(&&**w);
+        let all_placeholders = input_words.iter().all(&is_placeholder);
+        let placeholder_words = input_words.iter()
+                                           .filter(|w| {
+                                               is_placeholder(w) &&
+                                               (all_placeholders || !surname_words.contains(w))
+                                           })
+                                           .count();
+        score -= 0.4 * placeholder_words as f64;
+
+        let normalized = input_words.join(" ");
+        if PLACEHOLDER_NAMES.contains(&&*normalized) {
+            score -= 0.3;
+        }
+
+        for (i, word) in name.words.iter().enumerate() {
+            // Titles that survived parsing before the surname weren't in title
+            // position, e.g. "Analytics Department Smith" (but many are also
+            // surnames, e.g. "Martin Luther King")
+            if i < name.surname_index && title::is_prefix_title_part(word) {
+                score -= 0.2;
+            }
+
+            if word.chars().count() > 2 && is_missing_vowels(word) &&
+               !surname::is_vowelless_surname(word, true) {
+                score -= 0.3;
+            }
+        }
+
+        if input.split(is_nonalphanumeric).any(|w| w.chars().any(char::is_numeric)) {
+            score -= 0.3;
+        }
+
+        if name.words.len() > 1 && name.words.iter().all(|w| *w == name.words[0]) {
+            score -= 0.3;
+        }
+
+        score.clamp(0.0, 1.0)
+    }
+
+    /// Whether `plausibility` considers the input more likely than not to
+    /// be a person's name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use human_name::Name;
+    ///
+    /// assert!(Name::looks_like_person_name("Jane A. Smith"));
+    /// assert!(Name::looks_like_person_name("Zhang Wei"));
+    /// assert!(!Name::looks_like_person_name("Customer Service"));
+    /// assert!(!Name::looks_like_person_name("Acme Corp"));
+    /// assert!(!Name::looks_like_person_name("Smith & Sons, LLC"));
+    /// ```
+    pub fn looks_like_person_name(input: &str) -> bool {
+        Name::plausibility(input) >= PLAUSIBLE_THRESHOLD
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::Name;
+
+    #[test]
+    fn known_given_name() {
+        assert!(Name::plausibility("Mary Smith") > Name::plausibility("Qarlo Smith"));
+        assert!(Name::looks_like_person_name("Qarlo Smith"));
+    }
+
+    #[test]
+    fn organizations() {
+        assert_eq!(0.0, Name::plausibility("Acme Widgets Inc."));
+        assert_eq!(0.0, Name::plausibility("Müller GmbH"));
+        assert_eq!(0.0, Name::plausibility("Jones Brothers, L.L.C."));
+        assert_eq!(0.0, Name::plausibility("Acme Co."));
+        assert!(Name::looks_like_person_name("Zaldy Co"));
+        assert!(Name::looks_like_person_name("Kim Sa"));
+    }
+
+    #[test]
+    fn placeholders() {
+        assert!(!Name::looks_like_person_name("Test User"));
+        assert!(!Name::looks_like_person_name("Admin Admin"));
+        assert!(!Name::looks_like_person_name("Sales Team"));
+        assert!(Name::plausibility("John Doe") < Name::plausibility("John Dough"));
+        assert!(!Name::looks_like_person_name("Customer Service"));
+        assert!(!Name::looks_like_person_name("Test Smith"));
+    }
+
+    #[test]
+    fn real_names_like_placeholders_or_titles() {
+        assert!(Name::looks_like_person_name("Robert Service"));
+        assert!(Name::looks_like_person_name("Jane Last"));
+        assert!(Name::looks_like_person_name("Jim Bar"));
+        assert!(Name::looks_like_person_name("Martin Luther King"));
+        assert!(Name::looks_like_person_name("Martin Luther King, Jr."));
+        assert!(Name::looks_like_person_name("Elizabeth Bishop"));
+        assert!(Name::looks_like_person_name("Judith Judge"));
+        assert!(Name::looks_like_person_name("John Major"));
+    }
+
+    #[test]
+    fn keyboard_mashing() {
+        assert!(!Name::looks_like_person_name("Xkcd Qwrtz"));
+        assert!(!Name::looks_like_person_name("Jane Doe2"));
+    }
+
+    #[test]
+    fn unparseable() {
+        assert_eq!(0.0, Name::plausibility("Madonna"));
+        assert_eq!(0.0, Name::plausibility(""));
+    }
+}
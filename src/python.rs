@@ -0,0 +1,138 @@
+//! Python bindings, enabled by the `python` feature.
+//!
+//! Build and install into the current virtualenv with
+//! `maturin develop --features python`, then from Python:
+//!
+//! ```python
+//! from human_name import Name, parse_many
+//!
+//! name = Name.parse("Jane A. Doe")
+//! name.given_name                          # 'Jane'
+//! name.consistent_with(Name.parse("J. Doe")) # True
+//! parse_many(["Jane Doe", "foo@bar.com"])  # [<Name 'Jane Doe'>, None]
+//! ```
+
+// pyo3 0.20's `#[pymethods]` expands to impls nested in a static, which
+// newer compilers lint against
+#![allow(non_local_definitions)]
+
+use pyo3::prelude::*;
+use rustc_serialize::json::ToJson;
+use super::Name;
+
+/// A parsed name (see `human_name::Name`), exposed to Python as `Name`.
+///
+/// Equality and hashing follow the Rust `Eq` and `Hash` implementations, so
+/// the same caveats apply: equality is consistency, which isn't transitive.
+#[pyclass(name = "Name", module = "human_name")]
+pub struct PyName {
+    name: Name,
+}
+
+#[pymethods]
+impl PyName {
+    /// Returns None if the input can't be parsed
+    #[staticmethod]
+    fn parse(input: &str) -> Option<PyName> {
+        Name::parse(input).map(|name| PyName { name: name })
+    }
+
+    #[getter]
+    fn surname(&self) -> String {
+        self.name.surname().into_owned()
+    }
+
+    #[getter]
+    fn given_name(&self) -> Option<&str> {
+        self.name.given_name()
+    }
+
+    #[getter]
+    fn initials(&self) -> &str {
+        self.name.initials()
+    }
+
+    #[getter]
+    fn first_initial(&self) -> char {
+        self.name.first_initial()
+    }
+
+    #[getter]
+    fn middle_initials(&self) -> Option<&str> {
+        self.name.middle_initials()
+    }
+
+    #[getter]
+    fn middle_names(&self) -> Option<String> {
+        self.name.middle_name().map(|s| s.into_owned())
+    }
+
+    #[getter]
+    fn suffix(&self) -> Option<&str> {
+        self.name.suffix()
+    }
+
+    #[getter]
+    fn goes_by_middle_name(&self) -> bool {
+        self.name.goes_by_middle_name()
+    }
+
+    #[getter]
+    fn surname_hash(&self) -> u64 {
+        self.name.memoized_surname_hash()
+    }
+
+    fn display_full(&self) -> String {
+        self.name.display_full()
+    }
+
+    fn display_first_last(&self) -> String {
+        self.name.display_first_last()
+    }
+
+    fn display_initial_surname(&self) -> String {
+        self.name.display_initial_surname()
+    }
+
+    fn consistent_with(&self, other: &PyName) -> bool {
+        self.name.consistent_with(&other.name)
+    }
+
+    fn matches_slug_or_localpart(&self, input: &str) -> bool {
+        self.name.matches_slug_or_localpart(input)
+    }
+
+    fn to_json(&self) -> String {
+        self.name.to_json().to_string()
+    }
+
+    fn __eq__(&self, other: &PyName) -> bool {
+        self.name.consistent_with(&other.name)
+    }
+
+    fn __hash__(&self) -> u64 {
+        self.name.memoized_surname_hash()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("<Name '{}'>", self.name.display_full())
+    }
+}
+
+/// Parses a list of strings, returning a list of the same length containing
+/// a `Name` or None for each. Releases the GIL while parsing.
+#[pyfunction]
+fn parse_many(py: Python, inputs: Vec<String>) -> Vec<Option<PyName>> {
+    let names = py.allow_threads(move || {
+        inputs.iter().map(|input| Name::parse(input)).collect::<Vec<_>>()
+    });
+
+    names.into_iter().map(|name| name.map(|name| PyName { name: name })).collect()
+}
+
+#[pymodule]
+fn human_name(_py: Python, m: &PyModule) -> PyResult<()> {
+    m.add_class::<PyName>()?;
+    m.add_function(wrap_pyfunction!(self::parse_many, m)?)?;
+    Ok(())
+}
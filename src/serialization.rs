@@ -1,11 +1,56 @@
+#[cfg(not(target_arch = "wasm32"))]
 use rustc_serialize::json::{ToJson, Json};
 use std::collections::BTreeMap;
 use super::Name;
 
+// A serialized component of a name
+pub enum Field {
+    String(String),
+    Strings(Vec<String>),
+}
+
+// The components we serialize, by key (in the order they're output, since we
+// use a BTreeMap as rustc-serialize does for objects)
+pub fn fields(name: &Name) -> BTreeMap<&'static str, Field> {
+    let mut d = BTreeMap::new();
+    d.insert("surname", Field::String(name.surname().into_owned()));
+    d.insert("first_initial", Field::String(format!("{}", name.first_initial())));
+    if let Some(given_name) = name.given_name() {
+        d.insert("given_name", Field::String(given_name.to_string()));
+    }
+    if let Some(initials) = name.middle_initials() {
+        d.insert("middle_initials", Field::String(initials.to_string()));
+    }
+    if let Some(middle_name) = name.middle_name() {
+        d.insert("middle_names", Field::String(middle_name.into_owned()));
+    }
+    if let Some(suffix) = name.suffix() {
+        d.insert("suffix", Field::String(suffix.to_string()));
+    }
+    let patronymics = name.patronymics();
+    if !patronymics.is_empty() {
+        d.insert("patronymics",
+                 Field::Strings(patronymics.iter().map(|p| p.to_string()).collect()));
+    }
+    if let Some(title) = name.honorific_prefix() {
+        d.insert("honorific_prefix", Field::String(title.to_string()));
+    }
+    if let Some(title) = name.honorific_suffix() {
+        d.insert("honorific_suffix", Field::String(title.to_string()));
+    }
+    if let Some(nickname) = name.nickname() {
+        d.insert("nickname", Field::String(nickname.to_string()));
+    }
+    d
+}
+
+#[cfg(not(target_arch = "wasm32"))]
 impl ToJson for Name {
 
     /// Serializes a name into parsed components.
     ///
+    /// Not available under WebAssembly, where rustc-serialize doesn't build.
+    ///
     /// ```
     /// # extern crate rustc_serialize;
     /// # extern crate human_name;
@@ -22,22 +67,14 @@ impl ToJson for Name {
     /// # }
     /// ```
     fn to_json(&self) -> Json {
-        let mut d = BTreeMap::new();
-        d.insert("surname".to_string(), self.surname().to_json());
-        d.insert("first_initial".to_string(),
-                 format!("{}", self.first_initial()).to_json());
-        if let Some(name) = self.given_name() {
-            d.insert("given_name".to_string(), name.to_json());
-        }
-        if let Some(initials) = self.middle_initials() {
-            d.insert("middle_initials".to_string(), initials.to_json());
-        }
-        if let Some(name) = self.middle_name() {
-            d.insert("middle_names".to_string(), name.to_json());
-        }
-        if let Some(suffix) = self.suffix() {
-            d.insert("suffix".to_string(), suffix.to_json());
-        }
-        Json::Object(d)
+        Json::Object(fields(self).into_iter()
+                                 .map(|(key, field)| {
+                                     let value = match field {
+                                         Field::String(s) => s.to_json(),
+                                         Field::Strings(v) => v.to_json(),
+                                     };
+                                     (key.to_string(), value)
+                                 })
+                                 .collect())
     }
 }
@@ -0,0 +1,214 @@
+use super::{Name, NameWordOrInitial};
+use super::surname;
+use super::utils::{transliterate, lowercase_if_alpha};
+
+/// National conventions for alphabetizing names with surname particles,
+/// for use with `Name::sort_key`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortConvention {
+    /// Leading particles are ignored: "van den Berg" sorts as "Berg, van den"
+    Dutch,
+    /// Particles are part of the surname: "van den Berg" sorts under "V"
+    Belgian,
+    /// Lowercase particles are ignored, so "von Goethe" sorts as "Goethe,
+    /// von", but capitalized ones (usually of foreign origin, as in "De
+    /// Gruyter") are not
+    German,
+    /// Sorts by the first (paternal) surname, ignoring leading particles and
+    /// any conjunction between paternal and maternal surnames: "de la Fuente
+    /// y Ortiz" sorts as "Fuente Ortiz, de la"
+    Spanish,
+}
+
+// The particles each convention ignores, in lowercase. Particles outside
+// these lists, like the "San" of "San Martín" or the "Al" of "Al Saud", are
+// part of the principal surname, as they are in the name's own tradition.
+static DUTCH_PARTICLES: [&'static str; 17] = [
+    "'s", "'t", "aan", "de", "den", "der", "des", "du", "het", "in", "la", "le", "op", "te", "ten",
+    "ter", "van",
+];
+
+static GERMAN_PARTICLES: [&'static str; 17] = [
+    "am", "an", "auf", "de", "den", "der", "di", "du", "im", "ten", "ter", "van", "vom", "von", "zu",
+    "zum", "zur",
+];
+
+static SPANISH_PARTICLES: [&'static str; 5] = ["de", "del", "la", "las", "los"];
+
+impl SortConvention {
+    fn is_particle(&self, word: &str) -> bool {
+        let particles: &[&'static str] = match *self {
+            SortConvention::Dutch => &DUTCH_PARTICLES,
+            SortConvention::Belgian => &[],
+            SortConvention::German => &GERMAN_PARTICLES,
+            SortConvention::Spanish => &SPANISH_PARTICLES,
+        };
+        particles.contains(&&*word.to_lowercase())
+    }
+}
+
+impl Name {
+
+    /// A key for sorting names the way a librarian would under the given
+    /// convention: the principal part of the surname, then given names or
+    /// initials, then any ignored particles, then the generational suffix.
+    ///
+    /// The key is transliterated to ASCII and lowercased, so that comparing
+    /// keys with `Ord` gives a sensible order regardless of accents or case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use human_name::{Name, SortConvention};
+    ///
+    /// let name = Name::parse("Jan van den Berg").unwrap();
+    /// assert_eq!("berg, jan, van den", name.sort_key(SortConvention::Dutch));
+    /// assert_eq!("van den berg, jan", name.sort_key(SortConvention::Belgian));
+    ///
+    /// let name = Name::parse("José de la Fuente y Ortiz").unwrap();
+    /// assert_eq!("fuente ortiz, jose, de la", name.sort_key(SortConvention::Spanish));
+    /// ```
+    pub fn sort_key(&self, convention: SortConvention) -> String {
+        let surnames = self.surnames();
+        let particle_count = leading_particle_count(surnames, convention);
+
+        let principal = surnames[particle_count..]
+                            .iter()
+                            .filter(|w| {
+                                convention != SortConvention::Spanish ||
+                                !surname::is_conjunction(w)
+                            });
+
+        let mut key = fold_words(principal);
+
+        let given: Vec<String> = self.given_names_or_initials()
+                                     .map(|part| {
+                                         match part {
+                                             NameWordOrInitial::Word(name, _) => name.to_string(),
+                                             NameWordOrInitial::Initial(c) => c.to_string(),
+                                         }
+                                     })
+                                     .collect();
+        push_field(&mut key, fold_words(given.iter()));
+        push_field(&mut key, fold_words(surnames[0..particle_count].iter()));
+
+        if let Some(generation) = self.generation_from_suffix {
+            push_field(&mut key, format!("{:02}", generation));
+        }
+
+        key
+    }
+}
+
+// How many of the surname words are particles to set apart under the given
+// convention (e.g. the "van den" of "van den Berg" in Dutch)
+pub fn leading_particle_count(surnames: &[String], convention: SortConvention) -> usize {
+    let particle_count = match convention {
+        SortConvention::Belgian => 0,
+        SortConvention::German => {
+            surnames.iter()
+                    .take_while(|w| w.starts_with(char::is_lowercase) && convention.is_particle(w))
+                    .count()
+        }
+        SortConvention::Dutch | SortConvention::Spanish => {
+            surnames.iter().take_while(|w| convention.is_particle(w)).count()
+        }
+    };
+
+    // Never treat the whole surname as particles
+    if particle_count == surnames.len() {
+        0
+    } else {
+        particle_count
+    }
+}
+
+fn fold_words<'a, I: Iterator<Item = &'a String>>(words: I) -> String {
+    let mut result = String::new();
+    for word in words {
+        let folded = word.chars().flat_map(transliterate).filter_map(lowercase_if_alpha);
+        if !result.is_empty() {
+            result.push(' ');
+        }
+        result.extend(folded);
+    }
+    result
+}
+
+fn push_field(key: &mut String, field: String) {
+    if !field.is_empty() {
+        key.push_str(", ");
+        key.push_str(&field);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::Name;
+
+    fn sorted(names: &[&str], convention: SortConvention) -> Vec<String> {
+        let mut parsed: Vec<Name> = names.iter().map(|n| Name::parse(n).unwrap()).collect();
+        parsed.sort_by_key(|n| n.sort_key(convention));
+        parsed.iter().map(|n| n.display_full()).collect()
+    }
+
+    #[test]
+    fn dutch() {
+        let names = ["Piet van Vliet", "Jan van der Meer", "Anna Bakker"];
+        assert_eq!(vec!["Anna Bakker", "Jan van der Meer", "Piet van Vliet"],
+                   sorted(&names, SortConvention::Dutch));
+    }
+
+    #[test]
+    fn dutch_particles() {
+        assert_eq!("berg, jan, van den",
+                   Name::parse("Jan van den Berg").unwrap().sort_key(SortConvention::Dutch));
+        assert_eq!("berg, jan, van den",
+                   Name::parse("JAN VAN DEN BERG").unwrap().sort_key(SortConvention::Dutch));
+        assert_eq!("brink, jan, ten",
+                   Name::parse("Jan ten Brink").unwrap().sort_key(SortConvention::Dutch));
+        assert_eq!("hof, jan, van t",
+                   Name::parse("Jan van 't Hof").unwrap().sort_key(SortConvention::Dutch));
+        assert_eq!("al saud, hassan",
+                   Name::parse("Hassan Al Saud").unwrap().sort_key(SortConvention::Dutch));
+    }
+
+    #[test]
+    fn belgian() {
+        let names = ["Piet van Vliet", "Jan van der Meer", "Anna Bakker"];
+        assert_eq!(vec!["Anna Bakker", "Jan van der Meer", "Piet van Vliet"],
+                   sorted(&names, SortConvention::Belgian));
+        assert_eq!("van vliet, piet",
+                   Name::parse("Piet van Vliet").unwrap().sort_key(SortConvention::Belgian));
+    }
+
+    #[test]
+    fn german() {
+        let name = Name::parse("Johann Wolfgang von Goethe").unwrap();
+        assert_eq!("goethe, johann wolfgang, von", name.sort_key(SortConvention::German));
+
+        let name = Name::parse("Jan van den Berg").unwrap();
+        assert_eq!("berg, jan, van den", name.sort_key(SortConvention::German));
+    }
+
+    #[test]
+    fn spanish() {
+        let name = Name::parse("Juan Alberto T. Velasquez y Garcia III").unwrap();
+        assert_eq!("velasquez garcia, juan alberto t, 03",
+                   name.sort_key(SortConvention::Spanish));
+
+        let name = Name::parse("Oscar de la Hoya").unwrap();
+        assert_eq!("hoya, oscar, de la", name.sort_key(SortConvention::Spanish));
+
+        let name = Name::parse("José San Martín").unwrap();
+        assert_eq!("san martin, jose", name.sort_key(SortConvention::Spanish));
+    }
+
+    #[test]
+    fn accents_and_case() {
+        let a = Name::parse("ÉMILE ZOLA").unwrap();
+        let b = Name::parse("emile zola").unwrap();
+        assert_eq!(a.sort_key(SortConvention::Dutch), b.sort_key(SortConvention::Dutch));
+    }
+}
@@ -1,5 +1,5 @@
 use phf;
-use std::ascii::AsciiExt;
+use std::borrow::Cow;
 use super::namepart::NamePart;
 
 static VOWELLESS_SURNAMES: [&'static str; 4] = ["Ng", "Lv", "Mtz", "Hdz"];
@@ -22,6 +22,7 @@ static SURNAME_PREFIXES: phf::Set<&'static str> = phf_set! {
     "del",
     "dela",
     "dei",
+    "den",
     "der",
     "di",
     "dí",
@@ -31,6 +32,7 @@ static SURNAME_PREFIXES: phf::Set<&'static str> = phf_set! {
     "la",
     "le",
     "na",
+    "ten",
     "ter",
     "van",
     "vel",
@@ -45,9 +47,12 @@ static SURNAME_PREFIXES: phf::Set<&'static str> = phf_set! {
     "Av",
     "Aw",
     "Bar",
+    "Bat",
     "Ben",
     "Bon",
     "Bin",
+    "Bint",
+    "Binti",
     "Da",
     "Das",
     "Dal",
@@ -73,6 +78,7 @@ static SURNAME_PREFIXES: phf::Set<&'static str> = phf_set! {
     "Santa",
     "St",
     "Ste",
+    "Ten",
     "Ter",
     "Van",
     "Vel",
@@ -89,6 +95,90 @@ pub fn is_vowelless_surname(word: &str, use_capitalization: bool) -> bool {
     }
 }
 
+// Ignores case, since whether a particle is capitalized depends on the input
+// and on namecasing ("van Den Berg", "VAN DEN BERG")
+pub fn is_particle(word: &str) -> bool {
+    if SURNAME_PREFIXES.contains(word) {
+        return true;
+    }
+
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => {
+            let capitalized: String = first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect();
+            SURNAME_PREFIXES.contains(&*capitalized)
+        }
+        None => false,
+    }
+}
+
+pub fn is_conjunction(word: &str) -> bool {
+    SINGLE_LETTER_CONJUNCTIONS.contains(&word)
+}
+
+// Gendered surname endings, longest first within each family, and the
+// masculine ending each is replaced with, so that both forms of a surname
+// reduce to the same word. Slavic adjectival forms ("Kowalski", "Kowalska";
+// "Dostoyevsky", "Dostoyevskaya"), Russian and Bulgarian possessive forms
+// ("Ivanov", "Ivanova"), Polish forms ("Nowak", "Nowakowa"), Czech and Slovak
+// forms ("Novák", "Nováková"; "Novotný", "Novotná") and Lithuanian forms
+// ("Kazlauskas", "Kazlauskienė", "Kazlauskaitė"; "Petraitis", "Petraitytė"),
+// whose masculine endings are all reduced to "-as", since "-ienė" may be
+// the feminine form of any of them.
+//
+// Endings are matched against the lowercase, decomposed word, so Czech "-ová"
+// is distinct from Russian "-ova". There are deliberately no bare one- or
+// two-letter endings like "-a" or "-is", which would pair unrelated surnames
+// such as "Martin" and "Marta", or "Davis" and "Davy".
+static GENDERED_SURNAME_ENDINGS: [(&'static str, &'static str); 27] = [
+    ("skaya", "ski"),
+    ("skiy", "ski"),
+    ("skij", "ski"),
+    ("skii", "ski"),
+    ("sky", "ski"),
+    ("ska", "ski"),
+    ("dzka", "dzki"),
+    ("cka", "cki"),
+    ("aya", "oy"),
+    ("ova", "ov"),
+    ("eva", "ev"),
+    ("ina", "in"),
+    ("o\u{301}wna", ""),
+    ("owna", ""),
+    ("owa", ""),
+    ("ova\u{301}", ""),
+    ("a\u{301}", "y\u{301}"),
+    ("iene\u{307}", "as"),
+    ("iene", "as"),
+    ("aite\u{307}", "as"),
+    ("aite", "as"),
+    ("yte\u{307}", "as"),
+    ("yte", "as"),
+    ("ute\u{307}", "as"),
+    ("is", "as"),
+    ("ys", "as"),
+    ("us", "as"),
+];
+
+const MIN_GENDERED_SURNAME_STEM_LEN: usize = 3;
+
+// Reduces a lowercase, NFKD-normalized surname word to the form shared by its
+// masculine and feminine forms, if it has a recognized gendered ending
+pub fn strip_gendered_ending(word: &str) -> Cow<'_, str> {
+    for &(ending, replacement) in GENDERED_SURNAME_ENDINGS.iter() {
+        if word.ends_with(ending) && word.len() >= ending.len() + MIN_GENDERED_SURNAME_STEM_LEN {
+            let stem = &word[0..word.len() - ending.len()];
+            return if replacement.is_empty() {
+                Cow::Borrowed(stem)
+            } else {
+                Cow::Owned(format!("{}{}", stem, replacement))
+            };
+        }
+    }
+
+    Cow::Borrowed(word)
+}
+
 pub fn find_surname_index(words: &[NamePart]) -> usize {
     if words.len() < 2 {
         return 0;
@@ -116,6 +206,7 @@ pub fn find_surname_index(words: &[NamePart]) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use unicode_normalization::UnicodeNormalization;
     use super::super::namepart::{Location, NamePart};
 
     #[test]
@@ -190,4 +281,64 @@ mod tests {
                                 .collect();
         assert_eq!(1, find_surname_index(&*parts));
     }
+
+    fn stem(word: &str) -> String {
+        strip_gendered_ending(&word.nfkd().collect::<String>()).into_owned()
+    }
+
+    #[test]
+    fn gendered_surname_pairs() {
+        let pairs = [("novák", "nováková"),
+                     ("novotný", "novotná"),
+                     ("nový", "nová"),
+                     ("ivanov", "ivanova"),
+                     ("andreyev", "andreyeva"),
+                     ("pushkin", "pushkina"),
+                     ("tolstoy", "tolstaya"),
+                     ("dostoyevskiy", "dostoyevskaya"),
+                     ("dostoyevsky", "dostoyevskaya"),
+                     ("kowalski", "kowalska"),
+                     ("wisniewski", "wisniewska"),
+                     ("zawadzki", "zawadzka"),
+                     ("nowak", "nowakowa"),
+                     ("nowak", "nowakówna"),
+                     ("kazlauskas", "kazlauskienė"),
+                     ("kazlauskas", "kazlauskiene"),
+                     ("kazlauskas", "kazlauskaitė"),
+                     ("petraitis", "petraitienė"),
+                     ("petraitis", "petraitytė"),
+                     ("vaitkus", "vaitkutė")];
+
+        for &(masculine, feminine) in pairs.iter() {
+            assert_eq!(stem(masculine),
+                       stem(feminine),
+                       "{} and {} should share a stem",
+                       masculine,
+                       feminine);
+        }
+    }
+
+    #[test]
+    fn gendered_surname_non_pairs() {
+        let pairs = [("novák", "novotná"),
+                     ("ivanov", "ivanenko"),
+                     ("kowalski", "kowal"),
+                     ("martin", "marta"),
+                     ("davis", "davy"),
+                     ("lewis", "lewy")];
+
+        for &(a, b) in pairs.iter() {
+            assert!(stem(a) != stem(b),
+                    "{} and {} should not share a stem",
+                    a,
+                    b);
+        }
+    }
+
+    #[test]
+    fn short_gendered_surnames() {
+        assert_eq!("li", stem("li"));
+        assert_eq!("ova", stem("ova"));
+        assert_eq!("rosa", stem("rosa"));
+    }
 }
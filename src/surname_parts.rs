@@ -0,0 +1,231 @@
+use super::Name;
+use super::surname;
+
+/// The role of a piece of a surname (see `Name::surname_parts`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SurnamePartKind {
+    /// A particle such as "de la", "van" or "von"
+    Particle,
+    /// A conjunction joining paternal and maternal surnames, such as "y"
+    Conjunction,
+    /// Any other piece of the surname
+    Principal,
+}
+
+/// A piece of a surname: a word, or one side of a hyphenated compound.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SurnamePart<'a> {
+    /// The text of the piece, as in `Name::surname`
+    pub text: &'a str,
+    /// The role of the piece
+    pub kind: SurnamePartKind,
+    /// Whether the piece was joined to the previous one with a hyphen
+    /// (rather than a space)
+    pub hyphenated: bool,
+}
+
+/// The structure of a surname, as returned by `Name::surname_parts`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SurnameParts<'a> {
+    parts: Vec<SurnamePart<'a>>,
+}
+
+impl<'a> SurnameParts<'a> {
+    /// All pieces of the surname, in order
+    pub fn parts(&self) -> &[SurnamePart<'a>] {
+        &self.parts
+    }
+
+    /// The principal pieces of the surname, without particles or conjunctions
+    pub fn principal(&self) -> Vec<&'a str> {
+        self.parts
+            .iter()
+            .filter(|p| p.kind == SurnamePartKind::Principal)
+            .map(|p| p.text)
+            .collect()
+    }
+
+    /// Any particles preceding the rest of the surname, as in the Dutch
+    /// tussenvoegsel ("van der" in "van der Meer")
+    pub fn tussenvoegsel(&self) -> Option<String> {
+        let count = self.parts.iter().take_while(|p| p.kind == SurnamePartKind::Particle).count();
+        if count > 0 {
+            Some(join(&self.parts[0..count]))
+        } else {
+            None
+        }
+    }
+
+    /// For an Iberian-style surname, the first (usually paternal) surname,
+    /// including any particles: "Velasquez" in "Velasquez y Garcia", or "de la
+    /// Fuente" in "de la Fuente Ortiz".
+    ///
+    /// Surnames are split at a conjunction if there is one, or otherwise only
+    /// if they consist of exactly two principal pieces, each with any preceding
+    /// particles. A surname of one principal piece is entirely paternal.
+    /// Otherwise, the split is ambiguous and this returns None.
+    pub fn paternal(&self) -> Option<String> {
+        self.split_index().map(|(i, _)| join(&self.parts[0..i]))
+    }
+
+    /// For an Iberian-style surname, the second (usually maternal) surname,
+    /// if present: "Garcia" in "Velasquez y Garcia". See `paternal`.
+    pub fn maternal(&self) -> Option<String> {
+        match self.split_index() {
+            Some((_, Some(j))) => Some(join(&self.parts[j..])),
+            _ => None,
+        }
+    }
+
+    // Index of the end of the paternal surname, and start of the maternal
+    fn split_index(&self) -> Option<(usize, Option<usize>)> {
+        if let Some(i) = self.parts.iter().position(|p| p.kind == SurnamePartKind::Conjunction) {
+            return Some((i, Some(i + 1)));
+        }
+
+        let principal: Vec<usize> = self.parts
+                                        .iter()
+                                        .enumerate()
+                                        .filter(|&(_, p)| p.kind == SurnamePartKind::Principal)
+                                        .map(|(i, _)| i)
+                                        .collect();
+
+        match principal.len() {
+            1 => Some((self.parts.len(), None)),
+            2 => Some((principal[0] + 1, Some(principal[0] + 1))),
+            _ => None,
+        }
+    }
+}
+
+fn join(parts: &[SurnamePart]) -> String {
+    let mut result = String::new();
+    for part in parts {
+        if !result.is_empty() {
+            result.push(if part.hyphenated { '-' } else { ' ' });
+        }
+        result.push_str(part.text);
+    }
+    result
+}
+
+impl Name {
+
+    /// The surname, split into particles, conjunctions, and principal
+    /// pieces, with hyphenated compounds split into their components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use human_name::{Name, SurnamePartKind};
+    ///
+    /// let name = Name::parse("Juan Alberto T. Velasquez y Garcia").unwrap();
+    /// let parts = name.surname_parts();
+    /// assert_eq!(SurnamePartKind::Conjunction, parts.parts()[1].kind);
+    /// assert_eq!(Some("Velasquez".to_string()), parts.paternal());
+    /// assert_eq!(Some("Garcia".to_string()), parts.maternal());
+    ///
+    /// let name = Name::parse("Jan van der Meer").unwrap();
+    /// let parts = name.surname_parts();
+    /// assert_eq!(Some("van der".to_string()), parts.tussenvoegsel());
+    /// assert_eq!(vec!["Meer"], parts.principal());
+    ///
+    /// let name = Name::parse("Ann Smith-Jones").unwrap();
+    /// assert_eq!(vec!["Smith", "Jones"], name.surname_parts().principal());
+    /// ```
+    pub fn surname_parts(&self) -> SurnameParts<'_> {
+        let words = self.surnames();
+        let mut parts = Vec::with_capacity(words.len());
+
+        for (i, word) in words.iter().enumerate() {
+            let is_last = i == words.len() - 1;
+
+            let kind = if is_last {
+                SurnamePartKind::Principal
+            } else if i > 0 && surname::is_conjunction(word) {
+                SurnamePartKind::Conjunction
+            } else if surname::is_particle(word) {
+                SurnamePartKind::Particle
+            } else {
+                SurnamePartKind::Principal
+            };
+
+            if kind == SurnamePartKind::Principal {
+                for (j, piece) in word.split('-').filter(|p| !p.is_empty()).enumerate() {
+                    parts.push(SurnamePart {
+                        text: piece,
+                        kind: kind,
+                        hyphenated: j > 0,
+                    });
+                }
+            } else {
+                parts.push(SurnamePart {
+                    text: word,
+                    kind: kind,
+                    hyphenated: false,
+                });
+            }
+        }
+
+        SurnameParts { parts: parts }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::Name;
+
+    fn kinds(input: &str) -> Vec<(String, SurnamePartKind)> {
+        let name = Name::parse(input).unwrap();
+        let parts = name.surname_parts();
+        parts.parts().iter().map(|p| (p.text.to_string(), p.kind)).collect()
+    }
+
+    #[test]
+    fn simple() {
+        assert_eq!(vec![("Doe".to_string(), SurnamePartKind::Principal)],
+                   kinds("Jane Doe"));
+
+        let name = Name::parse("Jane Doe").unwrap();
+        assert_eq!(Some("Doe".to_string()), name.surname_parts().paternal());
+        assert_eq!(None, name.surname_parts().maternal());
+        assert_eq!(None, name.surname_parts().tussenvoegsel());
+    }
+
+    #[test]
+    fn particles() {
+        assert_eq!(vec![("de".to_string(), SurnamePartKind::Particle),
+                        ("la".to_string(), SurnamePartKind::Particle),
+                        ("Hoya".to_string(), SurnamePartKind::Principal)],
+                   kinds("Oscar de la Hoya"));
+    }
+
+    #[test]
+    fn tussenvoegsel() {
+        let name = Name::parse("Jan van den Berg").unwrap();
+        assert_eq!(Some("van den".to_string()), name.surname_parts().tussenvoegsel());
+        assert_eq!(vec!["Berg"], name.surname_parts().principal());
+
+        let name = Name::parse("JAN TEN BRINK").unwrap();
+        assert_eq!(Some("ten".to_string()), name.surname_parts().tussenvoegsel());
+    }
+
+    #[test]
+    fn iberian_without_conjunction() {
+        let name = Name::parse("de la Fuente Ortiz, José").unwrap();
+        let parts = name.surname_parts();
+        assert_eq!(Some("de la Fuente".to_string()), parts.paternal());
+        assert_eq!(Some("Ortiz".to_string()), parts.maternal());
+    }
+
+    #[test]
+    fn hyphenated() {
+        let name = Name::parse("Maria Garcia-Marquez").unwrap();
+        let parts = name.surname_parts();
+        assert!(parts.parts()[1].hyphenated);
+        assert_eq!(vec!["Garcia", "Marquez"], parts.principal());
+        assert_eq!(Some("Garcia".to_string()), parts.paternal());
+        assert_eq!(Some("Marquez".to_string()), parts.maternal());
+    }
+}
@@ -1,5 +1,4 @@
 use phf;
-use std::ascii::AsciiExt;
 use super::namepart::NamePart;
 
 static TWO_CHAR_TITLES: [&'static str; 4] = ["mr", "ms", "sr", "dr"];
@@ -402,6 +401,31 @@ static POSTFIX_TITLES: phf::Set<&'static str> = phf_set! {
     "Al",
 };
 
+// Titles and degrees which people commonly add to handles and display names,
+// excluding any that are plausible as names themselves (lowercase)
+static HANDLE_TITLES: phf::Set<&'static str> = phf_set! {
+    "dr",
+    "mr",
+    "mrs",
+    "ms",
+    "mx",
+    "prof",
+    "professor",
+    "rev",
+    "phd",
+    "md",
+    "dds",
+    "dmd",
+    "dvm",
+    "mba",
+    "mph",
+    "jd",
+    "esq",
+    "rn",
+    "msc",
+    "bsc",
+};
+
 fn might_be_title_part(word: &NamePart) -> bool {
     if word.chars < 3 {
         // Allow any word with 1 or 2 characters as part of a title (but see below)
@@ -429,7 +453,7 @@ fn might_be_last_title_part(word: &NamePart) -> bool {
 fn is_prefix_title(words: &[NamePart]) -> bool {
     match words.last() {
         Some(word) => {
-            if !might_be_last_title_part(&word) {
+            if !might_be_last_title_part(word) {
                 return false;
             }
         }
@@ -439,7 +463,7 @@ fn is_prefix_title(words: &[NamePart]) -> bool {
     }
 
     if words.len() > 1 {
-        words[0..words.len() - 1].iter().all(|word| might_be_title_part(&word))
+        words[0..words.len() - 1].iter().all(|word| might_be_title_part(word))
     } else {
         true
     }
@@ -455,6 +479,14 @@ pub fn is_postfix_title(word: &NamePart, might_be_initials: bool) -> bool {
     }
 }
 
+pub fn is_prefix_title_part(word: &str) -> bool {
+    PREFIX_TITLE_PARTS.contains(word)
+}
+
+pub fn is_handle_title(word: &str) -> bool {
+    word.len() <= 9 && HANDLE_TITLES.contains(&*word.to_ascii_lowercase())
+}
+
 pub fn strip_prefix_title<'a>(words: &mut Vec<NamePart<'a>>) -> Option<Vec<NamePart<'a>>> {
     let mut prefix_len = words.len() - 1;
     while prefix_len > 0 {
@@ -479,6 +511,13 @@ mod tests {
     use super::*;
     use super::super::namepart::{Location, NamePart};
 
+    #[test]
+    fn is_handle_title_degree() {
+        assert!(is_handle_title("PhD"));
+        assert!(is_handle_title("dr"));
+        assert!(!is_handle_title("King"));
+    }
+
     #[test]
     fn is_postfix_title_esq() {
         let part = NamePart::from_word("esq", true, Location::Start);
@@ -512,7 +551,7 @@ mod tests {
                                     .collect();
         strip_prefix_title(&mut parts);
         assert_eq!("Jane Doe",
-                   parts.iter().fold("".to_string(), |s, ref p| s + " " + p.word).trim());
+                   parts.iter().fold("".to_string(), |s, p| s + " " + p.word).trim());
     }
 
     #[test]
@@ -521,7 +560,7 @@ mod tests {
                                     .collect();
         strip_prefix_title(&mut parts);
         assert_eq!("Jane Doe",
-                   parts.iter().fold("".to_string(), |s, ref p| s + " " + p.word).trim());
+                   parts.iter().fold("".to_string(), |s, p| s + " " + p.word).trim());
     }
 
     #[test]
@@ -532,7 +571,7 @@ mod tests {
                                     .collect();
         strip_prefix_title(&mut parts);
         assert_eq!("Jane Doe",
-                   parts.iter().fold("".to_string(), |s, ref p| s + " " + p.word).trim());
+                   parts.iter().fold("".to_string(), |s, p| s + " " + p.word).trim());
     }
 
     #[test]
@@ -541,7 +580,7 @@ mod tests {
                                     .collect();
         strip_prefix_title(&mut parts);
         assert_eq!("Jane Doe",
-                   parts.iter().fold("".to_string(), |s, ref p| s + " " + p.word).trim());
+                   parts.iter().fold("".to_string(), |s, p| s + " " + p.word).trim());
     }
 
     #[test]
@@ -552,7 +591,7 @@ mod tests {
                                     .collect();
         strip_prefix_title(&mut parts);
         assert_eq!("Jane Doe",
-                   parts.iter().fold("".to_string(), |s, ref p| s + " " + p.word).trim());
+                   parts.iter().fold("".to_string(), |s, p| s + " " + p.word).trim());
     }
 
     #[test]
@@ -560,6 +599,6 @@ mod tests {
         let mut parts: Vec<_> = NamePart::all_from_text("Dr. Doe", true, Location::Start).collect();
         strip_prefix_title(&mut parts);
         assert_eq!("Doe",
-                   parts.iter().fold("".to_string(), |s, ref p| s + " " + p.word).trim());
+                   parts.iter().fold("".to_string(), |s, p| s + " " + p.word).trim());
     }
 }
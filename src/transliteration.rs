@@ -0,0 +1,562 @@
+use std::borrow::Cow;
+use std::fmt;
+use unicode_normalization::UnicodeNormalization;
+use unidecode::unidecode_char;
+
+/// A scheme for converting a word to Latin script for comparison (see
+/// `ComparisonOptions` and `Name::surname_hash_with`).
+///
+/// The output needn't be ASCII: any remaining non-ASCII characters are
+/// transliterated with unidecode, and case, accents and punctuation are
+/// ignored when comparing the results.
+///
+/// # Examples
+///
+/// ```
+/// use human_name::{Name, ComparisonOptions, GermanUmlauts};
+///
+/// let options = ComparisonOptions { transliterator: Some(&GermanUmlauts), ..ComparisonOptions::default() };
+/// let mueller = Name::parse("Jürgen Müller").unwrap();
+/// let mueller_ascii = Name::parse("Juergen Mueller").unwrap();
+///
+/// assert!(mueller.consistent_with_options(&mueller_ascii, &options));
+/// ```
+pub trait Transliterator: fmt::Debug {
+    /// Converts a word (which may be Unicode-normalized in any form) to
+    /// Latin script
+    fn transliterate(&self, word: &str) -> String;
+}
+
+/// The default scheme: context-free transliteration of each character using
+/// [unidecode](https://github.com/chowdhurya/rust-unidecode/), which e.g.
+/// gives Han characters their Mandarin readings, and drops accents.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Unidecode;
+
+impl Transliterator for Unidecode {
+    fn transliterate(&self, word: &str) -> String {
+        word.chars().map(unidecode_char).collect()
+    }
+}
+
+/// Expands German umlauts ("Müller" to "Mueller") and "ß" to "ss", as is
+/// conventional where they can't be written.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GermanUmlauts;
+
+impl Transliterator for GermanUmlauts {
+    fn transliterate(&self, word: &str) -> String {
+        let mut result = String::with_capacity(word.len() + 2);
+
+        for c in word.nfc() {
+            match c {
+                'ä' => result.push_str("ae"),
+                'ö' => result.push_str("oe"),
+                'ü' => result.push_str("ue"),
+                'Ä' => result.push_str("Ae"),
+                'Ö' => result.push_str("Oe"),
+                'Ü' => result.push_str("Ue"),
+                'ß' => result.push_str("ss"),
+                _ => result.push_str(unidecode_char(c)),
+            }
+        }
+
+        result
+    }
+}
+
+/// ISO 9 (GOST 7.79 System A) transliteration of Cyrillic, with one Latin
+/// letter per Cyrillic letter ("Жуков" to "Žukov"). Since accents are
+/// ignored in comparison, this will e.g. match "Zukov" but not "Zhukov".
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Iso9;
+
+impl Transliterator for Iso9 {
+    fn transliterate(&self, word: &str) -> String {
+        map_with_case(word, |c, _| iso9(c))
+    }
+}
+
+/// BGN/PCGN romanization of Cyrillic ("Евгений" to "Yevgeniy"), as used in
+/// English-language maps, news and many official records.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BgnPcgn;
+
+impl Transliterator for BgnPcgn {
+    fn transliterate(&self, word: &str) -> String {
+        map_with_case(word, bgn_pcgn)
+    }
+}
+
+/// Hepburn romanization of hiragana and katakana, as used in Japanese
+/// passports, without marking long vowels ("さとう" to "Sato").
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Hepburn;
+
+impl Transliterator for Hepburn {
+    fn transliterate(&self, word: &str) -> String {
+        let chars: Vec<char> = word.nfc().map(katakana_to_hiragana).collect();
+        let mut result = String::with_capacity(word.len());
+        let mut doubling_next = false;
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+            i += 1;
+
+            if c == 'っ' {
+                doubling_next = true;
+                continue;
+            } else if c == 'ー' {
+                continue;
+            }
+
+            let mut syllable = match kana(c) {
+                Some(syllable) => syllable.to_string(),
+                None => {
+                    result.push_str(unidecode_char(c));
+                    doubling_next = false;
+                    continue;
+                }
+            };
+
+            // Combine e.g. "き" and "ゃ" to "kya", or "し" and "ゃ" to "sha"
+            if let Some(vowel) = chars.get(i).and_then(|&c| small_y_kana(c)) {
+                if syllable.len() > 1 && syllable.ends_with('i') {
+                    syllable.pop();
+                    if !syllable.ends_with('h') && !syllable.ends_with('j') {
+                        syllable.push('y');
+                    }
+                    syllable.push(vowel);
+                    i += 1;
+                }
+            }
+
+            if c == 'ん' {
+                let next = chars.get(i).and_then(|&c| kana(c)).unwrap_or("");
+                if next.starts_with('b') || next.starts_with('m') || next.starts_with('p') {
+                    syllable = "m".to_string();
+                }
+            }
+
+            if doubling_next {
+                if syllable.starts_with("ch") {
+                    result.push('t');
+                } else if let Some(consonant) = syllable.chars().next() {
+                    result.push(consonant);
+                }
+                doubling_next = false;
+            }
+
+            result.push_str(&syllable);
+
+            // Leave long vowels unmarked ("おお", "おう" and "うう"), unless
+            // the second vowel is followed by another vowel, as in "いのうえ"
+            if syllable.ends_with('o') || syllable.ends_with('u') {
+                let next = chars.get(i).cloned();
+                let after_next = chars.get(i + 1).and_then(|&c| kana(c));
+                let is_long = match next {
+                    Some('う') => true,
+                    Some('お') => syllable.ends_with('o'),
+                    _ => false,
+                };
+                let vowel_follows = after_next.is_some_and(|s| s.len() == 1 && s != "n");
+
+                if is_long && !vowel_follows {
+                    i += 1;
+                }
+            }
+        }
+
+        capitalize_first(result)
+    }
+}
+
+/// Hanyu Pinyin, stripping tones whether they're written as marks or digits
+/// ("Lǚ3" to "Lu"), and treating "u:" as "ü", as well as "v" in the only
+/// syllables where it can stand for "ü" ("lv", "nv", "lve" and "nve"). Han
+/// characters are given their Mandarin readings.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Pinyin;
+
+impl Transliterator for Pinyin {
+    fn transliterate(&self, word: &str) -> String {
+        let mut result = String::with_capacity(word.len());
+        let chars: Vec<char> = word.nfkd().collect();
+
+        for (i, &c) in chars.iter().enumerate() {
+            match c {
+                'v' | 'V' if is_pinyin_u_umlaut(&chars, i) => {
+                    result.push(if c == 'v' { 'u' } else { 'U' });
+                }
+                '0'..='9' | ':' => {}
+                _ => {
+                    let reading = unidecode_char(c);
+                    if reading.len() > 1 && !result.is_empty() {
+                        // Han characters are read with a capital and a
+                        // trailing space ("Zheng ")
+                        result.push_str(&reading.trim().to_lowercase());
+                    } else {
+                        result.push_str(reading.trim());
+                    }
+                }
+            }
+        }
+
+        result
+    }
+}
+
+// Pinyin has no "v" of its own, but the letter is typed in place of "ü" after
+// "l" and "n", where "lu" and "lü" are distinct syllables. Otherwise we leave
+// it be, since "Victor" and "Olivia" aren't Pinyin at all.
+fn is_pinyin_u_umlaut(chars: &[char], i: usize) -> bool {
+    let lower = |c: char| c.to_lowercase().next().unwrap_or(c);
+
+    let after_initial = matches!(i.checked_sub(1).map(|j| lower(chars[j])), Some('l') | Some('n'));
+
+    let ends_syllable = |j: usize| chars.get(j).is_none_or(|&c| !c.is_alphabetic());
+    let ends_final = ends_syllable(i + 1) ||
+                     (chars.get(i + 1).map(|&c| lower(c)) == Some('e') && ends_syllable(i + 2));
+
+    after_initial && ends_final
+}
+
+// The word as converted by a non-default scheme, if any; callers still need
+// to apply the default transliteration to any remaining non-ASCII characters
+pub fn transliterate_with<'a>(word: &'a str, transliterator: Option<&dyn Transliterator>) -> Cow<'a, str> {
+    match transliterator {
+        Some(transliterator) => Cow::Owned(transliterator.transliterate(word)),
+        None => Cow::Borrowed(word),
+    }
+}
+
+// Maps each character of a composed word, passing the previous (lowercased)
+// character for context, and preserving the capitalization of the input
+fn map_with_case<F>(word: &str, f: F) -> String
+    where F: Fn(char, Option<char>) -> Option<&'static str>
+{
+    let mut result = String::with_capacity(word.len());
+    let mut prev = None;
+
+    for c in word.nfc() {
+        let lower = c.to_lowercase().next().unwrap_or(c);
+
+        match f(lower, prev) {
+            Some(latin) if c != lower => {
+                let mut latin_chars = latin.chars();
+                if let Some(first) = latin_chars.next() {
+                    result.extend(first.to_uppercase());
+                    result.extend(latin_chars);
+                }
+            }
+            Some(latin) => result.push_str(latin),
+            None => result.push_str(unidecode_char(c)),
+        }
+
+        prev = Some(lower);
+    }
+
+    result
+}
+
+fn iso9(c: char) -> Option<&'static str> {
+    let latin = match c {
+        'а' => "a",
+        'б' => "b",
+        'в' => "v",
+        'г' => "g",
+        'ґ' => "g̀",
+        'д' => "d",
+        'ђ' => "đ",
+        'ѓ' => "ǵ",
+        'е' => "e",
+        'ё' => "ë",
+        'є' => "ê",
+        'ж' => "ž",
+        'з' => "z",
+        'ѕ' => "ẑ",
+        'и' => "i",
+        'і' => "ì",
+        'ї' => "ï",
+        'й' => "j",
+        'ј' => "ǰ",
+        'к' => "k",
+        'л' => "l",
+        'љ' => "l̂",
+        'м' => "m",
+        'н' => "n",
+        'њ' => "n̂",
+        'о' => "o",
+        'п' => "p",
+        'р' => "r",
+        'с' => "s",
+        'т' => "t",
+        'ћ' => "ć",
+        'ќ' => "ḱ",
+        'у' => "u",
+        'ў' => "ǔ",
+        'ф' => "f",
+        'х' => "h",
+        'ц' => "c",
+        'ч' => "č",
+        'џ' => "d̂",
+        'ш' => "š",
+        'щ' => "ŝ",
+        'ъ' => "ʺ",
+        'ы' => "y",
+        'ь' => "ʹ",
+        'э' => "è",
+        'ю' => "û",
+        'я' => "â",
+        _ => return None,
+    };
+
+    Some(latin)
+}
+
+fn bgn_pcgn(c: char, prev: Option<char>) -> Option<&'static str> {
+    // "е" and "ё" are iotated at the start of a word and after a vowel, "й",
+    // "ъ" or "ь"
+    let iotated = match prev {
+        None => true,
+        Some(prev) => "аеёиоуыэюяйъьіїє".contains(prev),
+    };
+
+    let latin = match c {
+        'а' => "a",
+        'б' => "b",
+        'в' => "v",
+        'г' => "g",
+        'ґ' => "g",
+        'д' => "d",
+        'е' if iotated => "ye",
+        'е' => "e",
+        'ё' if iotated => "yë",
+        'ё' => "ë",
+        'є' => "ye",
+        'ж' => "zh",
+        'з' => "z",
+        'и' => "i",
+        'і' => "i",
+        'ї' => "yi",
+        'й' => "y",
+        'к' => "k",
+        'л' => "l",
+        'м' => "m",
+        'н' => "n",
+        'о' => "o",
+        'п' => "p",
+        'р' => "r",
+        'с' => "s",
+        'т' => "t",
+        'у' => "u",
+        'ф' => "f",
+        'х' => "kh",
+        'ц' => "ts",
+        'ч' => "ch",
+        'ш' => "sh",
+        'щ' => "shch",
+        'ъ' => "”",
+        'ы' => "y",
+        'ь' => "’",
+        'э' => "e",
+        'ю' => "yu",
+        'я' => "ya",
+        _ => return None,
+    };
+
+    Some(latin)
+}
+
+fn katakana_to_hiragana(c: char) -> char {
+    match c {
+        'ァ'..='ヶ' => ::std::char::from_u32(c as u32 - 0x60).unwrap_or(c),
+        _ => c,
+    }
+}
+
+fn small_y_kana(c: char) -> Option<char> {
+    match c {
+        'ゃ' => Some('a'),
+        'ゅ' => Some('u'),
+        'ょ' => Some('o'),
+        _ => None,
+    }
+}
+
+fn kana(c: char) -> Option<&'static str> {
+    let latin = match c {
+        'あ' | 'ぁ' => "a",
+        'い' | 'ぃ' | 'ゐ' => "i",
+        'う' | 'ぅ' => "u",
+        'え' | 'ぇ' | 'ゑ' => "e",
+        'お' | 'ぉ' | 'を' => "o",
+        'か' => "ka",
+        'き' => "ki",
+        'く' => "ku",
+        'け' => "ke",
+        'こ' => "ko",
+        'が' => "ga",
+        'ぎ' => "gi",
+        'ぐ' => "gu",
+        'げ' => "ge",
+        'ご' => "go",
+        'さ' => "sa",
+        'し' => "shi",
+        'す' => "su",
+        'せ' => "se",
+        'そ' => "so",
+        'ざ' => "za",
+        'じ' | 'ぢ' => "ji",
+        'ず' | 'づ' => "zu",
+        'ぜ' => "ze",
+        'ぞ' => "zo",
+        'た' => "ta",
+        'ち' => "chi",
+        'つ' => "tsu",
+        'て' => "te",
+        'と' => "to",
+        'だ' => "da",
+        'で' => "de",
+        'ど' => "do",
+        'な' => "na",
+        'に' => "ni",
+        'ぬ' => "nu",
+        'ね' => "ne",
+        'の' => "no",
+        'は' => "ha",
+        'ひ' => "hi",
+        'ふ' => "fu",
+        'へ' => "he",
+        'ほ' => "ho",
+        'ば' => "ba",
+        'び' => "bi",
+        'ぶ' => "bu",
+        'べ' => "be",
+        'ぼ' => "bo",
+        'ぱ' => "pa",
+        'ぴ' => "pi",
+        'ぷ' => "pu",
+        'ぺ' => "pe",
+        'ぽ' => "po",
+        'ま' => "ma",
+        'み' => "mi",
+        'む' => "mu",
+        'め' => "me",
+        'も' => "mo",
+        'や' | 'ゃ' => "ya",
+        'ゆ' | 'ゅ' => "yu",
+        'よ' | 'ょ' => "yo",
+        'ら' => "ra",
+        'り' => "ri",
+        'る' => "ru",
+        'れ' => "re",
+        'ろ' => "ro",
+        'わ' | 'ゎ' => "wa",
+        'ん' => "n",
+        'ゔ' => "vu",
+        _ => return None,
+    };
+
+    Some(latin)
+}
+
+fn capitalize_first(word: String) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => word,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher;
+    use super::super::{Name, ComparisonOptions};
+
+    fn hash_with(name: &Name, transliterator: &dyn Transliterator) -> u64 {
+        let mut s = DefaultHasher::new();
+        name.surname_hash_with(transliterator, &mut s);
+        s.finish()
+    }
+
+    #[test]
+    fn comparison() {
+        let cyrillic = Name::parse("Евгений Андреев").unwrap();
+        let latin = Name::parse("Yevgeniy Andreyev").unwrap();
+        let options = ComparisonOptions { transliterator: Some(&BgnPcgn), ..ComparisonOptions::default() };
+
+        assert!(cyrillic.consistent_with_options(&latin, &options));
+        assert!(!cyrillic.consistent_with(&latin));
+        assert_eq!(hash_with(&cyrillic, &BgnPcgn), hash_with(&latin, &BgnPcgn));
+    }
+
+    #[test]
+    fn unidecode() {
+        assert_eq!("Muller", Unidecode.transliterate("Müller"));
+        assert_eq!("Zhukov", Unidecode.transliterate("Жуков"));
+    }
+
+    #[test]
+    fn german_umlauts() {
+        assert_eq!("Mueller", GermanUmlauts.transliterate("Müller"));
+        assert_eq!("Mueller", GermanUmlauts.transliterate("Mu\u{308}ller"));
+        assert_eq!("Oezdemir", GermanUmlauts.transliterate("Özdemir"));
+        assert_eq!("Strasse", GermanUmlauts.transliterate("Straße"));
+    }
+
+    #[test]
+    fn iso9() {
+        assert_eq!("Žukov", Iso9.transliterate("Жуков"));
+        assert_eq!("Ŝukin", Iso9.transliterate("Щукин"));
+        assert_eq!("Âkovlev", Iso9.transliterate("Яковлев"));
+        assert_eq!("Hruŝëv", Iso9.transliterate("Хрущёв"));
+    }
+
+    #[test]
+    fn bgn_pcgn() {
+        assert_eq!("Zhukov", BgnPcgn.transliterate("Жуков"));
+        assert_eq!("Yevgeniy", BgnPcgn.transliterate("Евгений"));
+        assert_eq!("Khrushchëv", BgnPcgn.transliterate("Хрущёв"));
+        assert_eq!("Yakovlev", BgnPcgn.transliterate("Яковлев"));
+        assert_eq!("Andreyev", BgnPcgn.transliterate("Андреев"));
+        assert_eq!("Dostoyevskiy", BgnPcgn.transliterate("Достоевский"));
+    }
+
+    #[test]
+    fn hepburn() {
+        assert_eq!("Sato", Hepburn.transliterate("さとう"));
+        assert_eq!("Kono", Hepburn.transliterate("こうの"));
+        assert_eq!("Ono", Hepburn.transliterate("おおの"));
+        assert_eq!("Inoue", Hepburn.transliterate("いのうえ"));
+        assert_eq!("Hattori", Hepburn.transliterate("はっとり"));
+        assert_eq!("Kitchin", Hepburn.transliterate("キッチン"));
+        assert_eq!("Shota", Hepburn.transliterate("しょうた"));
+        assert_eq!("Namba", Hepburn.transliterate("なんば"));
+        assert_eq!("Suzuki", Hepburn.transliterate("スズキ"));
+        assert_eq!("Ryuji", Hepburn.transliterate("リュウジ"));
+    }
+
+    #[test]
+    fn pinyin() {
+        assert_eq!("Lu", Pinyin.transliterate("Lǚ"));
+        assert_eq!("Lu", Pinyin.transliterate("Lv"));
+        assert_eq!("Lu", Pinyin.transliterate("Lu:3"));
+        assert_eq!("Zhou", Pinyin.transliterate("Zhōu"));
+        assert_eq!("Zhou", Pinyin.transliterate("Zhou1"));
+        assert_eq!("Zhenghe", Pinyin.transliterate("鄭和"));
+        assert_eq!("Nue", Pinyin.transliterate("Nve4"));
+    }
+
+    #[test]
+    fn pinyin_leaves_other_names_alone() {
+        assert_eq!("Victor", Pinyin.transliterate("Victor"));
+        assert_eq!("Vivian", Pinyin.transliterate("Vivian"));
+        assert_eq!("Olivia", Pinyin.transliterate("Olivia"));
+        assert_eq!("Calvert", Pinyin.transliterate("Calvert"));
+        assert_eq!("Silva", Pinyin.transliterate("Silva"));
+    }
+}
@@ -1,4 +1,3 @@
-use std::ascii::AsciiExt;
 use std::str::Chars;
 use std::borrow::Cow;
 use unicode_normalization::char::canonical_combining_class;
@@ -28,7 +27,7 @@ pub fn is_mixed_case(s: &str) -> bool {
 }
 
 pub fn is_capitalized(word: &str) -> bool {
-    match word.chars().nth(0) {
+    match word.chars().next() {
         Some(c) => {
             if !c.is_uppercase() {
                 return false;
@@ -49,11 +48,7 @@ pub fn is_combining(c: char) -> bool {
 
 #[inline]
 pub fn is_ascii_alphabetic(c: char) -> bool {
-    match c {
-        'a'...'z' => true,
-        'A'...'Z' => true,
-        _ => false,
-    }
+    c.is_ascii_alphabetic()
 }
 
 // Sadly necessary because string split gives "type of this value must be known"
@@ -82,7 +77,7 @@ pub fn transliterate<'a>(c: char) -> Chars<'a> {
 #[inline]
 pub fn to_ascii_letter(c: char) -> Option<char> {
     match c {
-        'A'...'Z' => Some(c),
+        'A'..='Z' => Some(c),
         _ => match transliterate(c).next() {
             Some(c) => c.to_uppercase().next(),
             None => None,
@@ -90,7 +85,7 @@ pub fn to_ascii_letter(c: char) -> Option<char> {
     }
 }
 
-pub fn to_ascii(s: &str) -> Cow<str> {
+pub fn to_ascii(s: &str) -> Cow<'_, str> {
     if s.is_ascii() {
         Cow::Borrowed(s)
     } else {
@@ -114,6 +109,102 @@ pub fn to_ascii(s: &str) -> Cow<str> {
     }
 }
 
+// The lowercase letters of a surname word, transliterated to ASCII except
+// for umlauts and the Scandinavian "å", which are kept so that comparison
+// can match them against their digraph spellings on the other side only
+// ("ø" is kept as "ö", and "æ" spelled "ae"). The "aa" of "-aard" and
+// "-gaard" is folded to "a", so "Østergaard" and "Ostergard" are the same.
+pub fn comparable_spelling(word: &str) -> String {
+    let mut result = String::with_capacity(word.len());
+
+    for c in word.nfkd() {
+        let marked = match (c, result.chars().last()) {
+            ('\u{308}', Some('a')) => Some('ä'),
+            ('\u{308}', Some('o')) => Some('ö'),
+            ('\u{308}', Some('u')) => Some('ü'),
+            ('\u{30a}', Some('a')) => Some('å'),
+            _ => None,
+        };
+
+        match (c, marked) {
+            (_, Some(marked)) => {
+                result.pop();
+                result.push(marked);
+            }
+            ('æ', _) | ('Æ', _) => result.push_str("ae"),
+            ('ø', _) | ('Ø', _) => result.push('ö'),
+            _ => result.extend(transliterate(c).filter_map(lowercase_if_alpha)),
+        }
+    }
+
+    if result.contains("aard") {
+        result = result.replace("aard", "ard");
+    }
+
+    result
+}
+
+// The digraph spelling of a letter kept by `comparable_spelling`, e.g.
+// "ue" for "ü" and "aa" for "å"
+#[inline]
+pub fn vowel_digraph(c: char) -> Option<(char, char)> {
+    match c {
+        'ä' => Some(('a', 'e')),
+        'ö' => Some(('o', 'e')),
+        'ü' => Some(('u', 'e')),
+        'å' => Some(('a', 'a')),
+        _ => None,
+    }
+}
+
+// A letter kept by `comparable_spelling` without its umlaut or ring, so that
+// "Müller" still matches "Muller"
+#[inline]
+pub fn unmarked_letter(c: char) -> char {
+    vowel_digraph(c).map_or(c, |(first, _)| first)
+}
+
+// A coarser fold of a `comparable_spelling`, with marks dropped and every
+// "ae", "oe", "ue" and "aa" folded to its first letter, so that any two
+// spellings which compare as consistent fold to the same string (but not
+// vice versa, e.g. "Baez" and "Baz"); only suitable for hashing
+pub fn fold_vowel_digraphs(spelling: &str) -> String {
+    let letters = spelling.chars().map(unmarked_letter).collect::<Vec<_>>();
+    let mut result = String::with_capacity(letters.len());
+    let mut i = 0;
+
+    while i < letters.len() {
+        let c = letters[i];
+        result.push(c);
+
+        i += match (c, letters.get(i + 1)) {
+            ('a', Some(&'e')) | ('o', Some(&'e')) | ('u', Some(&'e')) | ('a', Some(&'a')) => 2,
+            _ => 1,
+        };
+    }
+
+    result
+}
+
+// The lowercase letters of a given name, transliterated to ASCII, with "ä",
+// "ö", "ü" and "å" spelled "ae", "oe", "ue" and "aa", so "Jürgen" and
+// "Juergen" are the same but "Michael" and "Michal" are not
+pub fn expand_umlauts(word: &str) -> String {
+    let mut result = String::with_capacity(word.len());
+
+    for c in word.nfkd() {
+        if c == '\u{308}' && (result.ends_with('a') || result.ends_with('o') || result.ends_with('u')) {
+            result.push('e');
+        } else if c == '\u{30a}' && result.ends_with('a') {
+            result.push('a');
+        } else {
+            result.extend(transliterate(c).filter_map(lowercase_if_alpha));
+        }
+    }
+
+    result
+}
+
 pub fn capitalize_and_normalize(word: &str) -> String {
     let mut capitalize_next = true;
 
@@ -142,7 +233,7 @@ pub fn is_missing_vowels(word: &str) -> bool {
 }
 
 pub fn starts_with_consonant(word: &str) -> bool {
-    match word.chars().nth(0) {
+    match word.chars().next() {
         Some(c) => {
             c.is_alphabetic() && c.is_ascii() && (c == 'y' || c == 'Y' || !VOWELS.contains(c))
         }
@@ -152,18 +243,11 @@ pub fn starts_with_consonant(word: &str) -> bool {
 
 pub fn has_sequential_alphas(word: &str) -> bool {
     let mut iter = word.chars().peekable();
-    loop {
-        match iter.next() {
-            Some(c) => {
-                match iter.peek() {
-                    Some(nc) => {
-                        if c.is_alphabetic() && nc.is_alphabetic() {
-                            return true;
-                        }
-                    }
-                    None => {
-                        break;
-                    }
+    while let Some(c) = iter.next() {
+        match iter.peek() {
+            Some(nc) => {
+                if c.is_alphabetic() && nc.is_alphabetic() {
+                    return true;
                 }
             }
             None => {
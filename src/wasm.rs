@@ -0,0 +1,125 @@
+//! JavaScript bindings for use from WebAssembly, enabled by the `wasm` feature.
+//!
+//! Build with `wasm-pack build -- --features wasm`, then from JavaScript:
+//!
+//! ```js
+//! import { Name } from "human_name";
+//!
+//! const name = Name.parse("Jane A. Doe");
+//! name.givenName;                         // "Jane"
+//! name.consistentWith(Name.parse("J. Doe")); // true
+//! name.free();
+//! ```
+
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Object, Reflect, JSON};
+use super::Name;
+use super::serialization::{self, Field};
+
+/// A parsed name (see `human_name::Name`), exposed to JavaScript as `Name`.
+///
+/// As with any object from `wasm-bindgen`, call `free()` when done with it.
+#[wasm_bindgen(js_name = Name)]
+pub struct JsName {
+    name: Name,
+}
+
+#[wasm_bindgen(js_class = Name)]
+impl JsName {
+    /// Returns `undefined` if the input can't be parsed
+    pub fn parse(input: &str) -> Option<JsName> {
+        Name::parse(input).map(|name| JsName { name: name })
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn surname(&self) -> String {
+        self.name.surname().into_owned()
+    }
+
+    #[wasm_bindgen(getter, js_name = givenName)]
+    pub fn given_name(&self) -> Option<String> {
+        self.name.given_name().map(|s| s.to_string())
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn initials(&self) -> String {
+        self.name.initials().to_string()
+    }
+
+    #[wasm_bindgen(getter, js_name = firstInitial)]
+    pub fn first_initial(&self) -> String {
+        self.name.first_initial().to_string()
+    }
+
+    #[wasm_bindgen(getter, js_name = middleInitials)]
+    pub fn middle_initials(&self) -> Option<String> {
+        self.name.middle_initials().map(|s| s.to_string())
+    }
+
+    #[wasm_bindgen(getter, js_name = middleNames)]
+    pub fn middle_names(&self) -> Option<String> {
+        self.name.middle_name().map(|s| s.into_owned())
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn suffix(&self) -> Option<String> {
+        self.name.suffix().map(|s| s.to_string())
+    }
+
+    #[wasm_bindgen(getter, js_name = goesByMiddleName)]
+    pub fn goes_by_middle_name(&self) -> bool {
+        self.name.goes_by_middle_name()
+    }
+
+    #[wasm_bindgen(js_name = displayFull)]
+    pub fn display_full(&self) -> String {
+        self.name.display_full()
+    }
+
+    #[wasm_bindgen(js_name = displayFirstLast)]
+    pub fn display_first_last(&self) -> String {
+        self.name.display_first_last()
+    }
+
+    #[wasm_bindgen(js_name = displayInitialSurname)]
+    pub fn display_initial_surname(&self) -> String {
+        self.name.display_initial_surname()
+    }
+
+    #[wasm_bindgen(js_name = consistentWith)]
+    pub fn consistent_with(&self, other: &JsName) -> bool {
+        self.name.consistent_with(&other.name)
+    }
+
+    #[wasm_bindgen(js_name = matchesSlugOrLocalpart)]
+    pub fn matches_slug_or_localpart(&self, input: &str) -> bool {
+        self.name.matches_slug_or_localpart(input)
+    }
+
+    /// The same JSON as the command-line client outputs, as a string (not
+    /// named `toJSON`, so that `JSON.stringify` won't double-encode it)
+    #[wasm_bindgen(js_name = toJsonString)]
+    pub fn to_json_string(&self) -> String {
+        // rustc-serialize doesn't build for WebAssembly, so we let JavaScript
+        // do the encoding
+        let object = Object::new();
+        for (key, field) in serialization::fields(&self.name) {
+            let value: JsValue = match field {
+                Field::String(s) => s.into(),
+                Field::Strings(v) => v.into_iter().map(JsValue::from).collect::<Array>().into(),
+            };
+            Reflect::set(&object, &key.into(), &value).unwrap();
+        }
+
+        JSON::stringify(&object).unwrap().into()
+    }
+}
+
+/// Exposed separately from `Name.parse` for convenience
+#[wasm_bindgen(js_name = consistentWith)]
+pub fn consistent_with(a: &str, b: &str) -> bool {
+    match (Name::parse(a), Name::parse(b)) {
+        (Some(a), Some(b)) => a.consistent_with(&b),
+        _ => false,
+    }
+}
@@ -1,13 +1,225 @@
 use itertools::Itertools;
 use std::borrow::Cow;
+use std::ops::Range;
 use super::Name;
+use super::title;
 use super::utils::*;
 
+// Words people add to handles which tell us nothing about their name (lowercase)
+const HANDLE_DECORATIONS: [&'static str; 3] = ["official", "real", "the"];
+
+/// How a slug or localpart was found to match a name (see `Name::match_slug`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SlugMatchMethod {
+    /// The input had enough punctuation to parse as a name in its own right,
+    /// and that name was consistent with this one
+    Parsed,
+    /// The input consisted of all the name's initials, e.g. "jad"
+    AllInitials,
+    /// The input consisted of the given name plus surname initial(s), e.g. "janed"
+    GivenNameAndSurnameInitial,
+    /// All or part of the surname was found in the input, and anything before
+    /// or after it was consistent with the rest of the name
+    Surname,
+}
+
+/// What a run of characters before or after the surname was matched against
+/// (see `Name::match_slug`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SlugPart {
+    /// The full given name(s), e.g. "jane"
+    GivenName,
+    /// A prefix of the given name(s), e.g. "ja"
+    PartialGivenName,
+    /// Something starting with the first initial, when the given name is unknown
+    PossibleGivenName,
+    /// All or some of the first and middle initials, e.g. "ja"
+    Initials,
+    /// Something short starting with the first initial, when middle initials
+    /// are unknown
+    PossibleInitials,
+    /// The given name(s) plus all or some of the middle initials, e.g. "janea"
+    GivenNameAndInitials,
+    /// The given name(s) plus a short remainder, when middle initials are
+    /// unknown
+    GivenNameAndPossibleInitials,
+    /// A prefix of the given name plus the middle initials, e.g. "jna"
+    PartialGivenNameAndInitials,
+    /// Just the first initial, for a name that goes by its middle name
+    FirstInitial,
+}
+
+/// Details of a match between a name and a slug or localpart, as returned
+/// by `Name::match_slug`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SlugMatch {
+    /// How the match was found
+    pub method: SlugMatchMethod,
+    /// Byte range of the input where the surname (or, for the initials-based
+    /// methods, the surname initials) was found, if known
+    pub surname: Option<Range<usize>>,
+    /// Whether the whole surname was found, rather than just a prefix or initials
+    pub full_surname: bool,
+    /// What the part of the input before the surname matched, if any
+    pub prefix: Option<SlugPart>,
+    /// What the part of the input after the surname matched, if any
+    pub suffix: Option<SlugPart>,
+    /// Byte ranges of runs of digits which were ignored in matching
+    pub noise: Vec<Range<usize>>,
+}
+
+// Maps each character of the normalized input to its byte range in the original
+struct NormalizedSlug<'a> {
+    normed: Cow<'a, str>,
+    offsets: Option<Vec<(usize, usize, usize)>>,
+}
+
+impl<'a> NormalizedSlug<'a> {
+    fn new(string: &str) -> NormalizedSlug<'_> {
+        if string.chars().all(|c| c.is_alphabetic() && c.is_lowercase()) {
+            return NormalizedSlug {
+                normed: Cow::Borrowed(string),
+                offsets: None,
+            };
+        }
+
+        let mut normed = String::with_capacity(string.len());
+        let mut offsets = Vec::with_capacity(string.len());
+        for (i, c) in string.char_indices() {
+            if let Some(lower) = lowercase_if_alpha(c) {
+                offsets.push((normed.len(), i, i + c.len_utf8()));
+                normed.push(lower);
+            }
+        }
+
+        NormalizedSlug {
+            normed: Cow::Owned(normed),
+            offsets: Some(offsets),
+        }
+    }
+
+    fn input_span(&self, begin: usize, end: usize) -> Range<usize> {
+        match self.offsets {
+            None => begin..end,
+            Some(ref offsets) => {
+                let first = offsets.iter().position(|&(n, _, _)| n >= begin).unwrap();
+                let last = offsets.iter().rposition(|&(n, _, _)| n < end).unwrap();
+                offsets[first].1..offsets[last].2
+            }
+        }
+    }
+}
+
+fn is_handle_decoration(token: &str) -> bool {
+    token.chars().all(char::is_numeric) || title::is_handle_title(token) ||
+    HANDLE_DECORATIONS.iter().any(|d| d.eq_ignore_ascii_case(token))
+}
+
+// Byte ranges of the pieces of a camelCase word, also splitting digits from letters
+fn camel_case_boundaries(word: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut begin = 0;
+    let mut prev: Option<char> = None;
+    let mut chars = word.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        if let Some(p) = prev {
+            let next_is_lower = chars.peek().map(|&(_, n)| n.is_lowercase()).unwrap_or(false);
+            let is_boundary = (p.is_lowercase() && c.is_uppercase()) ||
+                              (p.is_uppercase() && c.is_uppercase() && next_is_lower) ||
+                              p.is_numeric() != c.is_numeric();
+            if is_boundary {
+                ranges.push(begin..i);
+                begin = i;
+            }
+        }
+        prev = Some(c);
+    }
+
+    if begin < word.len() {
+        ranges.push(begin..word.len());
+    }
+    ranges
+}
+
+// Splits a handle into the tokens which might be name parts, e.g.
+// "@JaneDoe_PhD" into "Jane" and "Doe"
+fn handle_tokens(handle: &str) -> Vec<&str> {
+    let mut tokens = Vec::new();
+
+    for piece in handle.split(is_nonalphanumeric).filter(|p| !p.is_empty()) {
+        if is_handle_decoration(piece) {
+            continue;
+        }
+
+        let ranges = camel_case_boundaries(piece);
+        let mut i = 0;
+        while i < ranges.len() {
+            // Catch decorations split by their own capitalization, e.g. "PhD"
+            if i + 1 < ranges.len() &&
+               is_handle_decoration(&piece[ranges[i].start..ranges[i + 1].end]) {
+                i += 2;
+                continue;
+            }
+
+            let token = &piece[ranges[i].clone()];
+            if !is_handle_decoration(token) {
+                tokens.push(token);
+            }
+            i += 1;
+        }
+    }
+
+    tokens
+}
+
+// Candidate slugs for the given components, in rough order of popularity
+fn localpart_patterns(given_name: Option<&str>, surname: &str, initials: &str) -> Vec<String> {
+    let lower = |s: &str| -> String { s.chars().filter_map(lowercase_if_alpha).collect() };
+
+    let last = lower(surname);
+    let initials = lower(initials);
+    let first_initial: String = initials.chars().take(1).collect();
+
+    let mut patterns = Vec::with_capacity(6);
+    if let Some(name) = given_name {
+        let first = lower(name);
+        patterns.push(format!("{}.{}", first, last));
+        patterns.push(format!("{}{}", first, last));
+        patterns.push(format!("{}{}", first_initial, last));
+        patterns.push(format!("{}_{}", first, last));
+    } else {
+        patterns.push(format!("{}{}", first_initial, last));
+    }
+    patterns.push(format!("{}{}", last, first_initial));
+    patterns.push(format!("{}{}", initials, last));
+    patterns
+}
+
+fn find_noise(string: &str) -> Vec<Range<usize>> {
+    let mut noise: Vec<Range<usize>> = Vec::new();
+    for (i, c) in string.char_indices() {
+        if c.is_numeric() {
+            let end = i + c.len_utf8();
+            if let Some(range) = noise.last_mut() {
+                if range.end == i {
+                    range.end = end;
+                    continue;
+                }
+            }
+            noise.push(i..end);
+        }
+    }
+    noise
+}
+
 impl Name {
 
     /// Does this name appear to match a munged string such as an email
     /// localpart or URL slug, where whitespace has been removed?
     ///
+    /// Equivalent to `match_slug(string).is_some()`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -29,33 +241,145 @@ impl Name {
     ///
     /// ```
     pub fn matches_slug_or_localpart(&self, string: &str) -> bool {
-        if string.is_empty() {
+        self.match_slug(string).is_some()
+    }
+
+    /// Does this name appear to match a social media handle or display name,
+    /// such as "@JaneDoe_PhD", "jdoe1987" or "Dr Jane D."?
+    ///
+    /// Splits the input on camelCase boundaries and punctuation, ignores
+    /// digits, titles and decorations like "official" or "real", and then
+    /// matches what remains like `matches_slug_or_localpart`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use human_name::Name;
+    /// let name = Name::parse("Jane A. Doe").unwrap();
+    ///
+    /// assert!(name.matches_handle("@JaneDoe_PhD"));
+    /// assert!(name.matches_handle("jdoe1987"));
+    /// assert!(name.matches_handle("Dr Jane D."));
+    /// assert!(name.matches_handle("RealJaneDoe"));
+    /// assert!(!name.matches_handle("@JohnDoe"));
+    /// ```
+    pub fn matches_handle(&self, handle: &str) -> bool {
+        let tokens = handle_tokens(handle.trim().trim_start_matches('@'));
+        if tokens.is_empty() {
             return false;
         }
 
+        self.matches_slug_or_localpart(&tokens.join("."))
+    }
+
+    /// Likely email localparts or URL slugs for this name, most common
+    /// patterns first: "jane.doe", "janedoe", "jdoe", "jane_doe", "doej" and
+    /// "jadoe", followed by the same patterns transliterated to ASCII if the
+    /// name contains other characters.
+    ///
+    /// Candidates are filtered through `matches_slug_or_localpart`, so every
+    /// one returned matches the name (and ASCII variants which it wouldn't
+    /// recognize, such as "bjornsorensen" for "Björn Sørensen", are omitted).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use human_name::Name;
+    ///
+    /// let name = Name::parse("Björn A. Sørensen").unwrap();
+    /// let candidates = name.candidate_localparts();
+    /// assert_eq!("bjorn.sørensen", candidates[0]);
+    /// assert!(candidates.contains(&"basørensen".to_string()));
+    /// assert!(candidates.contains(&"bjorn.sorensen".to_string()));
+    /// ```
+    pub fn candidate_localparts(&self) -> Vec<String> {
+        let given_name = self.given_name();
+        let surname = self.surnames().concat();
+        let mut candidates = localpart_patterns(given_name, &surname, self.initials());
+
+        if !surname.is_ascii() || !given_name.map(|n| n.is_ascii()).unwrap_or(true) {
+            let ascii_given_name = given_name.map(to_ascii);
+            let ascii_surname = to_ascii(&surname);
+            let ascii_initials = to_ascii(self.initials());
+            candidates.extend(localpart_patterns(ascii_given_name.as_deref(),
+                                                 &ascii_surname,
+                                                 &ascii_initials));
+        }
+
+        let mut result: Vec<String> = Vec::with_capacity(candidates.len());
+        for candidate in candidates {
+            if !result.contains(&candidate) && self.matches_slug_or_localpart(&candidate) {
+                result.push(candidate);
+            }
+        }
+        result
+    }
+
+    /// Like `matches_slug_or_localpart`, but describes how the input matched,
+    /// so that callers can score matches rather than accept any of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use human_name::{Name, SlugMatchMethod, SlugPart};
+    /// let name = Name::parse("Jane A. Doe").unwrap();
+    ///
+    /// let m = name.match_slug("JaneDoe2005").unwrap();
+    /// assert_eq!(SlugMatchMethod::Surname, m.method);
+    /// assert_eq!(Some(4..7), m.surname);
+    /// assert!(m.full_surname);
+    /// assert_eq!(Some(SlugPart::GivenName), m.prefix);
+    /// assert_eq!(None, m.suffix);
+    /// assert_eq!(vec![7..11], m.noise);
+    ///
+    /// let m = name.match_slug("jane.a.doe").unwrap();
+    /// assert_eq!(SlugMatchMethod::Parsed, m.method);
+    /// assert_eq!(Some(7..10), m.surname);
+    ///
+    /// let m = name.match_slug("doe_ja").unwrap();
+    /// assert_eq!(Some(0..3), m.surname);
+    /// assert_eq!(Some(SlugPart::PartialGivenName), m.suffix);
+    ///
+    /// let m = name.match_slug("jad").unwrap();
+    /// assert_eq!(SlugMatchMethod::AllInitials, m.method);
+    /// assert!(!m.full_surname);
+    ///
+    /// assert!(name.match_slug("johndoe").is_none());
+    /// ```
+    pub fn match_slug(&self, string: &str) -> Option<SlugMatch> {
+        if string.is_empty() {
+            return None;
+        }
+
+        let noise = find_noise(string);
+        let slug = NormalizedSlug::new(string);
+        let normed: &str = &slug.normed;
+
         // Special case: Nice punctuation lets us actually parse a name directly
         if string.chars().any(is_nonalphanumeric) {
             let subbed = string.split(is_nonalphanumeric)
-                               .filter(|p| p.len() > 0)
+                               .filter(|p| !p.is_empty())
                                .join(" ");
 
             if let Some(name) = Name::parse(&subbed) {
                 if name.consistent_with(self) {
-                    return true;
+                    let surname = self.find_surname_in(normed)
+                                      .map(|(begin, len, _)| slug.input_span(begin, begin + len));
+
+                    return Some(SlugMatch {
+                        method: SlugMatchMethod::Parsed,
+                        surname: surname,
+                        full_surname: true,
+                        prefix: None,
+                        suffix: None,
+                        noise: noise,
+                    });
                 }
             }
         }
 
-        let normed: Cow<str> = if string.chars().all(|c| c.is_alphabetic() && c.is_lowercase()) {
-            Cow::Borrowed(string)
-        } else {
-            Cow::Owned(string.chars()
-                             .filter_map(lowercase_if_alpha)
-                             .collect::<String>())
-        };
-
         if normed.is_empty() {
-            return false;
+            return None;
         }
 
         // Special case: Full initials
@@ -68,8 +392,11 @@ impl Name {
                                 .filter_map(|n| n.chars().nth(0))
                                 .flat_map(char::to_lowercase));
 
-            if *normed == initials {
-                return true;
+            if normed == initials {
+                return Some(self.surname_initials_match(&slug,
+                                                        SlugMatchMethod::AllInitials,
+                                                        SlugPart::Initials,
+                                                        noise));
             }
         }
 
@@ -84,8 +411,11 @@ impl Name {
                                             .filter_map(|n| n.chars().nth(0))
                                             .flat_map(char::to_lowercase));
 
-                if *normed == name_and_initial {
-                    return true;
+                if normed == name_and_initial {
+                    return Some(self.surname_initials_match(&slug,
+                                                            SlugMatchMethod::GivenNameAndSurnameInitial,
+                                                            SlugPart::GivenName,
+                                                            noise));
                 }
             }
         }
@@ -95,12 +425,7 @@ impl Name {
         // We find as much of the surname as we can, treat the rest of the input
         // as prefix and suffix, and examine those to see if they might match the
         // rest of the name.
-        let search_result = self.find_surname_in(&normed);
-        if search_result.is_none() {
-            return false;
-        }
-
-        let (match_begin, match_len, found_exact_surname) = search_result.unwrap();
+        let (match_begin, match_len, found_exact_surname) = self.find_surname_in(normed)?;
 
         let prefix = if match_begin > 0 {
             Some(&normed[0..match_begin])
@@ -117,19 +442,69 @@ impl Name {
         if prefix.map(|s| s.len()).unwrap_or(0) < 2 && suffix.map(|s| s.len()).unwrap_or(0) < 2 {
             // Don't allow just a two-letter surname match to result in an overall match
             if match_len < 3 {
-                return false;
+                return None;
             }
 
             // Don't allow just a 3 or 4-char part-surname match to result in an overall match
             if match_len < 5 && !found_exact_surname {
-                return false;
+                return None;
             }
         }
 
         let allow_unknowns = found_exact_surname && (prefix.is_none() || suffix.is_none());
 
-        (prefix.is_none() || self.matches_remaining_name_parts(prefix.unwrap(), allow_unknowns)) &&
-        (suffix.is_none() || self.matches_remaining_name_parts(suffix.unwrap(), allow_unknowns))
+        let prefix_part = match prefix {
+            Some(part) => {
+                match self.match_remaining_name_parts(part, allow_unknowns) {
+                    Some(matched) => Some(matched),
+                    None => return None,
+                }
+            }
+            None => None,
+        };
+
+        let suffix_part = match suffix {
+            Some(part) => {
+                match self.match_remaining_name_parts(part, allow_unknowns) {
+                    Some(matched) => Some(matched),
+                    None => return None,
+                }
+            }
+            None => None,
+        };
+
+        Some(SlugMatch {
+            method: SlugMatchMethod::Surname,
+            surname: Some(slug.input_span(match_begin, match_begin + match_len)),
+            full_surname: found_exact_surname,
+            prefix: prefix_part,
+            suffix: suffix_part,
+            noise: noise,
+        })
+    }
+
+    // For the special cases where the input ends with the surname initial(s)
+    fn surname_initials_match(&self,
+                              slug: &NormalizedSlug,
+                              method: SlugMatchMethod,
+                              prefix: SlugPart,
+                              noise: Vec<Range<usize>>)
+                              -> SlugMatch {
+        let surname_begin = slug.normed
+                                .char_indices()
+                                .rev()
+                                .nth(self.surnames().len() - 1)
+                                .map(|(i, _)| i)
+                                .unwrap_or(0);
+
+        SlugMatch {
+            method: method,
+            surname: Some(slug.input_span(surname_begin, slug.normed.len())),
+            full_surname: false,
+            prefix: Some(prefix),
+            suffix: None,
+            noise: noise,
+        }
     }
 
     fn find_surname_in(&self, haystack: &str) -> Option<(usize, usize, bool)> {
@@ -153,14 +528,10 @@ impl Name {
             }
         }
 
-        if let Some(i) = match_begin {
-            Some((i, match_len, match_len == lower_surname.len()))
-        } else {
-            None
-        }
+        match_begin.map(|i| (i, match_len, match_len == lower_surname.len()))
     }
 
-    fn matches_remaining_name_parts(&self, part: &str, allow_unknowns: bool) -> bool {
+    fn match_remaining_name_parts(&self, part: &str, allow_unknowns: bool) -> Option<SlugPart> {
         let lower_first_initial = self.first_initial().to_lowercase().next().unwrap();
         let given_names: Option<Cow<String>> = if self.surname_index == 1 {
             Some(Cow::Borrowed(&self.words[0]))
@@ -173,27 +544,31 @@ impl Name {
         if let Some(ref name) = given_names {
             // Allow just given name, or partial given name, as part
             if name.len() >= part.len() && eq_or_starts_with!(part, name) {
-                return true;
+                if name.chars().filter_map(lowercase_if_alpha).count() == part.chars().count() {
+                    return Some(SlugPart::GivenName);
+                } else {
+                    return Some(SlugPart::PartialGivenName);
+                }
             }
         } else if allow_unknowns {
             // Allow possible given name starting with first initial when given
             // name is unknown and surname matched exactly
             if part.starts_with(lower_first_initial) {
-                return true;
+                return Some(SlugPart::PossibleGivenName);
             }
         }
 
         if self.middle_initials().is_some() {
             // Allow just initials, or partial initials, as part
             if self.initials().len() >= part.len() && eq_or_starts_with!(part, self.initials()) {
-                return true;
+                return Some(SlugPart::Initials);
             }
         } else if allow_unknowns {
             // Allow possible initials starting with first initial when middle
             // initials are unknown and surname matched exactly (assuming maximum
             // likely number of first & middle initials is three)
             if part.len() < 4 && part.starts_with(lower_first_initial) {
-                return true;
+                return Some(SlugPart::PossibleInitials);
             }
         }
 
@@ -207,11 +582,11 @@ impl Name {
                 if let Some(initials) = self.middle_initials() {
                     if initials.len() >= remainder.len() &&
                        eq_or_starts_with!(remainder, initials) {
-                        return true;
+                        return Some(SlugPart::GivenNameAndInitials);
                     }
                 } else if allow_unknowns {
                     if remainder.len() < 3 {
-                        return true;
+                        return Some(SlugPart::GivenNameAndPossibleInitials);
                     }
                 }
             }
@@ -224,7 +599,7 @@ impl Name {
                 // Allow partial given name, plus known middle initials, as part
                 if let Some(name) = self.given_name() {
                     if eq_or_starts_with!(remainder, name) {
-                        return true;
+                        return Some(SlugPart::PartialGivenNameAndInitials);
                     }
                 }
             }
@@ -232,9 +607,73 @@ impl Name {
 
         if self.goes_by_middle_name() && part.len() == lower_first_initial.len_utf8() &&
            part.chars().nth(0) == Some(lower_first_initial) {
-            return true;
+            return Some(SlugPart::FirstInitial);
         }
 
-        false
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::handle_tokens;
+    use super::super::Name;
+
+    #[test]
+    fn handle_tokens_camel_case() {
+        assert_eq!(vec!["Jane", "Doe"], handle_tokens("JaneDoe_PhD"));
+        assert_eq!(vec!["JANE", "Doe"], handle_tokens("JANEDoe"));
+        assert_eq!(vec!["jdoe"], handle_tokens("jdoe1987"));
+        assert_eq!(vec!["Jane", "D"], handle_tokens("Dr Jane D."));
+        assert_eq!(vec!["Jane", "Doe"], handle_tokens("TheRealJaneDoeOfficial"));
+    }
+
+    #[test]
+    fn matches_handle() {
+        let name = Name::parse("Jane Doe").unwrap();
+        assert!(name.matches_handle("@JaneDoe_PhD"));
+        assert!(name.matches_handle("DrJaneDoe"));
+        assert!(name.matches_handle("jane_doe_2005"));
+        assert!(name.matches_handle("@doe.jane"));
+        assert!(!name.matches_handle("@JohnDoe"));
+        assert!(!name.matches_handle("@PhD"));
+        assert!(!name.matches_handle("1987"));
+    }
+
+    #[test]
+    fn candidate_localparts_order() {
+        let name = Name::parse("Jane A. Doe").unwrap();
+        assert_eq!(vec!["jane.doe", "janedoe", "jdoe", "jane_doe", "doej", "jadoe"],
+                   name.candidate_localparts());
+    }
+
+    #[test]
+    fn candidate_localparts_without_given_name() {
+        let name = Name::parse("J. A. Doe").unwrap();
+        assert_eq!(vec!["jdoe", "doej", "jadoe"], name.candidate_localparts());
+    }
+
+    #[test]
+    fn candidate_localparts_all_match() {
+        let names = ["Jane A. Doe",
+                     "MR OSCAR DE LA HOYA JR",
+                     "José Muñoz",
+                     "Ann-Marie O'Reilly",
+                     "T. Boone Pickens",
+                     "Björn Sørensen",
+                     "Smith, J. R. R."];
+
+        for input in names.iter() {
+            let name = Name::parse(input).unwrap();
+            let candidates = name.candidate_localparts();
+            assert!(candidates.len() >= 3, "{}: {:?}", input, candidates);
+
+            for candidate in candidates {
+                assert!(name.matches_slug_or_localpart(&candidate),
+                        "{} should match {}",
+                        input,
+                        candidate);
+            }
+        }
     }
 }
@@ -0,0 +1,206 @@
+/*
+ * Exercises the C API against a built library. Run with `make -C tests/c`
+ * after `cargo build`.
+ */
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "human_name.h"
+
+static int failures = 0;
+
+#define CHECK(cond)                                                       \
+    do {                                                                  \
+        if (!(cond)) {                                                    \
+            fprintf(stderr, "%s:%d: check failed: %s\n",                  \
+                    __FILE__, __LINE__, #cond);                           \
+            failures++;                                                   \
+        }                                                                 \
+    } while (0)
+
+static void check_string(char *actual, const char *expected) {
+    if (expected == NULL) {
+        CHECK(actual == NULL);
+    } else {
+        CHECK(actual != NULL && strcmp(actual, expected) == 0);
+    }
+
+    if (actual != NULL) {
+        human_name_free_string(actual);
+    }
+}
+
+static void test_parse(void) {
+    HumanName *name = human_name_parse("Dr. Jane A. Doe, PhD");
+    CHECK(name != NULL);
+
+    check_string(human_name_surname(name), "Doe");
+    check_string(human_name_given_name(name), "Jane");
+    check_string(human_name_middle_initials(name), "A");
+    check_string(human_name_display_full(name), "Jane A. Doe");
+    check_string(human_name_honorific_prefix(name), NULL);
+
+    human_name_free_name(name);
+
+    CHECK(human_name_parse("foo@bar.com") == NULL);
+}
+
+static void test_parse_ex(void) {
+    HumanNameParseOptions options = HUMAN_NAME_PARSE_OPTIONS_INIT;
+    options.preserve_extras = true;
+    int error = -1;
+
+    HumanName *name = human_name_parse_ex("Dr. Robert 'Bob' Roberts, PhD", &options, &error);
+    CHECK(name != NULL);
+    CHECK(error == HUMAN_NAME_OK);
+
+    check_string(human_name_honorific_prefix(name), "Dr.");
+    check_string(human_name_honorific_suffix(name), "PhD");
+    check_string(human_name_nickname(name), "Bob");
+
+    human_name_free_name(name);
+
+    name = human_name_parse_ex("Jane Doe", NULL, NULL);
+    CHECK(name != NULL);
+    human_name_free_name(name);
+}
+
+static void test_errors(void) {
+    HumanNameParseOptions options = HUMAN_NAME_PARSE_OPTIONS_INIT;
+    int error = -1;
+
+    CHECK(human_name_parse_ex(NULL, NULL, &error) == NULL);
+    CHECK(error == HUMAN_NAME_ERROR_NULL_INPUT);
+
+    CHECK(human_name_parse_ex("\xff\xfe", NULL, &error) == NULL);
+    CHECK(error == HUMAN_NAME_ERROR_INVALID_UTF8);
+
+    CHECK(human_name_parse_ex("12345", NULL, &error) == NULL);
+    CHECK(error == HUMAN_NAME_ERROR_NO_LETTERS);
+
+    CHECK(human_name_parse_ex("foo@bar.com", NULL, &error) == NULL);
+    CHECK(error == HUMAN_NAME_ERROR_UNPARSEABLE);
+
+    char long_input[2000];
+    memset(long_input, 'a', sizeof(long_input) - 1);
+    long_input[sizeof(long_input) - 1] = '\0';
+    CHECK(human_name_parse_ex(long_input, NULL, &error) == NULL);
+    CHECK(error == HUMAN_NAME_ERROR_TOO_LONG);
+
+    options.version = HUMAN_NAME_API_VERSION + 1;
+    CHECK(human_name_parse_ex("Jane Doe", &options, &error) == NULL);
+    CHECK(error == HUMAN_NAME_ERROR_UNSUPPORTED_VERSION);
+
+    CHECK(strcmp(human_name_error_message(HUMAN_NAME_ERROR_UNPARSEABLE),
+                 "input could not be parsed as a name") == 0);
+}
+
+static void test_comparison(void) {
+    HumanName *a = human_name_parse("Jane Doe");
+    HumanName *b = human_name_parse("J. Doe");
+    HumanName *c = human_name_parse("John Doe");
+
+    CHECK(human_name_consistent_with(a, b));
+    CHECK(!human_name_consistent_with(a, c));
+    CHECK(human_name_hash(a) == human_name_hash(b));
+    CHECK(human_name_matches_slug_or_localpart(a, "jane.doe"));
+
+    human_name_free_name(a);
+    human_name_free_name(b);
+    human_name_free_name(c);
+}
+
+static void test_batch(void) {
+    const char *inputs[] = { "Jane Doe", "foo@bar.com", "J. Doe", "John Doe", "Bob Smith" };
+    size_t n = sizeof(inputs) / sizeof(inputs[0]);
+
+    HumanName *names[5];
+    int errors[5];
+    uint64_t hashes[5];
+    HumanNameBatch batch = { names, errors, hashes };
+
+    CHECK(human_name_parse_batch(inputs, n, NULL, &batch) == 4);
+    CHECK(names[0] != NULL && errors[0] == HUMAN_NAME_OK);
+    CHECK(names[1] == NULL && errors[1] == HUMAN_NAME_ERROR_UNPARSEABLE && hashes[1] == 0);
+    CHECK(hashes[0] == human_name_hash(names[0]));
+    CHECK(hashes[0] == hashes[2]);
+
+    bool matrix[25];
+    CHECK(human_name_match_matrix((const HumanName *const *) names, n,
+                                  (const HumanName *const *) names, n, matrix) == HUMAN_NAME_OK);
+    CHECK(matrix[0 * n + 2]);   /* Jane Doe, J. Doe */
+    CHECK(!matrix[0 * n + 3]);  /* Jane Doe, John Doe */
+    CHECK(!matrix[1 * n + 1]);  /* Unparsed */
+    CHECK(matrix[2 * n + 3]);   /* J. Doe, John Doe */
+    CHECK(!matrix[4 * n + 0]);  /* Bob Smith, Jane Doe */
+
+    CHECK(human_name_match_matrix(NULL, 0, NULL, 0, NULL) == HUMAN_NAME_OK);
+    CHECK(human_name_match_matrix(NULL, n, (const HumanName *const *) names, n, matrix) ==
+          HUMAN_NAME_ERROR_NULL_INPUT);
+    CHECK(human_name_match_matrix((const HumanName *const *) names, SIZE_MAX,
+                                  (const HumanName *const *) names, 2, matrix) ==
+          HUMAN_NAME_ERROR_OVERFLOW);
+
+    size_t clusters[5];
+    CHECK(human_name_cluster((const HumanName *const *) names, n, clusters) == 3);
+    CHECK(clusters[0] == 0 && clusters[2] == 0 && clusters[3] == 0);
+    CHECK(clusters[1] == 1);
+    CHECK(clusters[4] == 2);
+
+    /* Hashes only, without allocating names */
+    uint64_t hashes_only[5];
+    HumanNameBatch hash_batch = { NULL, NULL, hashes_only };
+    CHECK(human_name_parse_batch(inputs, n, NULL, &hash_batch) == 4);
+    CHECK(hashes_only[0] == hashes[0] && hashes_only[1] == 0);
+
+    human_name_free_batch(names, n);
+}
+
+static void test_buffers(void) {
+    HumanName *name = human_name_parse("JOHN ALLEN Q DE LA MACDONALD JR");
+    char buf[64];
+    size_t len = 0;
+
+    CHECK(human_name_surname_buf(name, buf, sizeof(buf), &len) == HUMAN_NAME_OK);
+    CHECK(len == strlen("de la MacDonald") && strcmp(buf, "de la MacDonald") == 0);
+
+    CHECK(human_name_display_full_buf(name, buf, 8, &len) == HUMAN_NAME_ERROR_BUFFER_TOO_SMALL);
+    CHECK(len == strlen("John Allen Q. de la MacDonald, Jr."));
+    CHECK(human_name_display_full_buf(name, buf, len + 1, &len) == HUMAN_NAME_OK);
+    CHECK(strcmp(buf, "John Allen Q. de la MacDonald, Jr.") == 0);
+
+    CHECK(human_name_nickname_buf(name, buf, sizeof(buf), &len) == HUMAN_NAME_NOT_PRESENT);
+    CHECK(len == 0);
+
+    const char *given = human_name_given_name_ref(name, &len);
+    CHECK(given != NULL && len == 4 && strncmp(given, "John", len) == 0);
+
+    const char *suffix = human_name_suffix_ref(name, &len);
+    CHECK(suffix != NULL && len == 3 && strncmp(suffix, "Jr.", len) == 0);
+
+    CHECK(human_name_nickname_ref(name, &len) == NULL);
+    CHECK(len == 0);
+
+    human_name_free_name(name);
+}
+
+int main(void) {
+    CHECK(human_name_api_version() == HUMAN_NAME_API_VERSION);
+
+    test_parse();
+    test_parse_ex();
+    test_errors();
+    test_comparison();
+    test_batch();
+    test_buffers();
+
+    if (failures > 0) {
+        fprintf(stderr, "%d check(s) failed\n", failures);
+        return 1;
+    }
+
+    printf("ok\n");
+    return 0;
+}
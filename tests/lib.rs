@@ -1,6 +1,11 @@
+// These tests read data files, which isn't possible under wasm
+#![cfg(not(target_arch = "wasm32"))]
+
 extern crate human_name;
 extern crate unicode_normalization;
+extern crate cbindgen;
 
+use std::env;
 use std::io::prelude::*;
 use std::io::BufReader;
 use std::fs::File;
@@ -31,17 +36,17 @@ fn parsing() {
         let surname = parts[1];
         let given_name = parts[2];
         let middle_names = parts[3];
-        let first_initial = parts[4].chars().nth(0).unwrap();
+        let first_initial = parts[4].chars().next().unwrap();
         let middle_initials = parts[5];
         let suffix = parts[6];
 
         let name = human_name::Name::parse(input);
-        assert!(!name.is_none(), "[{}] Could not parse!", input);
+        assert!(name.is_some(), "[{}] Could not parse!", input);
 
-        let given_name = none_if_empty(&given_name);
-        let middle_names = none_if_empty(&middle_names);
-        let middle_initials = none_if_empty(&middle_initials);
-        let suffix = none_if_empty(&suffix);
+        let given_name = none_if_empty(given_name);
+        let middle_names = none_if_empty(middle_names);
+        let middle_initials = none_if_empty(middle_initials);
+        let suffix = none_if_empty(suffix);
 
         let name = name.unwrap();
         assert!(name.surname() == surname,
@@ -97,6 +102,33 @@ fn unparseable() {
     }
 }
 
+#[test]
+fn patronymics() {
+    let f = File::open("tests/patronymics.txt").ok().unwrap();
+    let reader = BufReader::new(f);
+
+    for line in reader.lines() {
+        let line = line.ok().unwrap();
+
+        if line.starts_with("#") {
+            continue;
+        }
+
+        let parts: Vec<&str> = line.split('|').collect();
+        let input = parts[0];
+        let expected: Vec<String> = parts[1].split(',')
+                                            .filter(|p| !p.is_empty())
+                                            .map(|p| p.nfkd().collect())
+                                            .collect();
+
+        let name = human_name::Name::parse(input);
+        assert!(name.is_some(), "{} was not parsed", input);
+
+        let name = name.unwrap();
+        assert_eq!(expected, name.patronymics(), "Wrong patronymics for {}", input);
+    }
+}
+
 #[test]
 fn equality() {
     let f = File::open("tests/equal-names.txt").ok().unwrap();
@@ -114,8 +146,8 @@ fn equality() {
         let b = parts[1];
         let expect = parts[2];
 
-        let parsed_a = human_name::Name::parse(&a);
-        let parsed_b = human_name::Name::parse(&b);
+        let parsed_a = human_name::Name::parse(a);
+        let parsed_b = human_name::Name::parse(b);
 
         assert!(parsed_a.is_some(), "{} was not parsed", a);
         assert!(parsed_b.is_some(), "{} was not parsed", b);
@@ -185,3 +217,24 @@ fn web_nonmatch() {
         assert!(!name.matches_slug_or_localpart(compare), "{} should not match {} but did!", name.display_full(), compare);
     }
 }
+
+// The C header is generated by cbindgen (see cbindgen.toml), so check it's up
+// to date with the Rust side of the C API, regenerating it if asked to
+#[test]
+fn c_header_is_generated() {
+    let path = "include/human_name.h";
+    let bindings = cbindgen::generate(env!("CARGO_MANIFEST_DIR")).unwrap();
+
+    if env::var_os("HUMAN_NAME_GENERATE_HEADER").is_some() {
+        bindings.write_to_file(path);
+    }
+
+    let mut generated = vec![];
+    bindings.write(&mut generated);
+    let mut header = String::new();
+    File::open(path).unwrap().read_to_string(&mut header).unwrap();
+
+    assert!(String::from_utf8(generated).unwrap() == header,
+            "{} is out of date; run `HUMAN_NAME_GENERATE_HEADER=1 cargo test c_header` to regenerate it",
+            path);
+}
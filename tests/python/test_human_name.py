@@ -0,0 +1,96 @@
+# Run with:
+#   maturin develop --features python
+#   python -m unittest discover tests/python
+
+import sys
+import threading
+import time
+import unittest
+
+from human_name import Name, parse_many
+
+
+class TestParse(unittest.TestCase):
+    def test_components(self):
+        name = Name.parse("Dr. Jane Ann Doe, Jr.")
+        self.assertEqual(name.surname, "Doe")
+        self.assertEqual(name.given_name, "Jane")
+        self.assertEqual(name.middle_names, "Ann")
+        self.assertEqual(name.middle_initials, "A")
+        self.assertEqual(name.first_initial, "J")
+        self.assertEqual(name.initials, "JA")
+        self.assertEqual(name.suffix, "Jr.")
+        self.assertFalse(name.goes_by_middle_name)
+
+    def test_initials_only(self):
+        name = Name.parse("J. Doe")
+        self.assertIsNone(name.given_name)
+        self.assertIsNone(name.middle_names)
+        self.assertEqual(name.display_first_last(), "J. Doe")
+
+    def test_unparseable(self):
+        self.assertIsNone(Name.parse("foo@bar.com"))
+
+    def test_display(self):
+        name = Name.parse("DOE, JANE A.")
+        self.assertEqual(name.display_full(), "Jane A. Doe")
+        self.assertEqual(name.display_initial_surname(), "J. Doe")
+
+
+class TestComparison(unittest.TestCase):
+    def test_consistent_with(self):
+        a = Name.parse("Jane Doe")
+        self.assertTrue(a.consistent_with(Name.parse("J. A. Doe")))
+        self.assertFalse(a.consistent_with(Name.parse("John Doe")))
+
+    def test_eq_and_hash(self):
+        a = Name.parse("Jane Doe")
+        b = Name.parse("J. Doe")
+        self.assertEqual(a, b)
+        self.assertEqual(hash(a), hash(b))
+        self.assertEqual(a.surname_hash, b.surname_hash)
+
+    def test_matches_slug_or_localpart(self):
+        name = Name.parse("Jane Doe")
+        self.assertTrue(name.matches_slug_or_localpart("janedoe"))
+        self.assertFalse(name.matches_slug_or_localpart("johnsmith"))
+
+
+class TestParseMany(unittest.TestCase):
+    def test_parse_many(self):
+        names = parse_many(["Jane Doe", "foo@bar.com", "Smith, John"])
+        self.assertEqual(len(names), 3)
+        self.assertEqual(names[0].surname, "Doe")
+        self.assertIsNone(names[1])
+        self.assertEqual(names[2].given_name, "John")
+
+    def test_parse_many_releases_gil(self):
+        # Another thread only gets to run in the middle of the call if the
+        # GIL is released (at its edges, it may get one switch interval)
+        ticks = []
+        done = threading.Event()
+
+        def tick():
+            while not done.is_set():
+                ticks.append(time.perf_counter())
+                time.sleep(0.0005)
+
+        interval = sys.getswitchinterval()
+        sys.setswitchinterval(0.0001)
+        ticker = threading.Thread(target=tick)
+        ticker.start()
+        try:
+            start = time.perf_counter()
+            parse_many(["Dr. Jane Ann Doe, Jr."] * 50000)
+            end = time.perf_counter()
+        finally:
+            done.set()
+            ticker.join()
+            sys.setswitchinterval(interval)
+
+        quarter = (end - start) / 4
+        self.assertTrue(any(start + quarter < t < end - quarter for t in ticks))
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,51 @@
+// Run with `wasm-pack test --node -- --features wasm`
+#![cfg(all(target_arch = "wasm32", feature = "wasm"))]
+
+extern crate human_name;
+extern crate wasm_bindgen_test;
+
+use human_name::wasm::{self, JsName};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn parse() {
+    let name = JsName::parse("MR OSCAR DE LA HOYA JR").unwrap();
+    assert_eq!("de la Hoya", name.surname());
+    assert_eq!(Some("Oscar".to_string()), name.given_name());
+    assert_eq!("O", name.first_initial());
+    assert_eq!(None, name.middle_initials());
+    assert_eq!(Some("Jr.".to_string()), name.suffix());
+    assert_eq!("Oscar de la Hoya, Jr.", name.display_full());
+    assert_eq!("O. de la Hoya", name.display_initial_surname());
+}
+
+#[wasm_bindgen_test]
+fn unparseable() {
+    assert!(JsName::parse("foo@bar.com").is_none());
+}
+
+#[wasm_bindgen_test]
+fn consistent_with() {
+    let jane = JsName::parse("Jane Doe").unwrap();
+    let j = JsName::parse("J. Doe").unwrap();
+    let john = JsName::parse("John Doe").unwrap();
+
+    assert!(jane.consistent_with(&j));
+    assert!(!jane.consistent_with(&john));
+    assert!(wasm::consistent_with("Jane Doe", "Doe, J."));
+    assert!(!wasm::consistent_with("Jane Doe", "foo@bar.com"));
+}
+
+#[wasm_bindgen_test]
+fn matches_slug_or_localpart() {
+    let name = JsName::parse("Jane A. Doe").unwrap();
+    assert!(name.matches_slug_or_localpart("jane.doe"));
+    assert!(!name.matches_slug_or_localpart("john.doe"));
+}
+
+#[wasm_bindgen_test]
+fn to_json_string() {
+    let name = JsName::parse("Jane Doe").unwrap();
+    assert_eq!(r#"{"first_initial":"J","given_name":"Jane","surname":"Doe"}"#,
+               name.to_json_string());
+}